@@ -0,0 +1,10 @@
+use relation::{num_bigint::BigInt, Relation};
+
+fn main() {
+    // `x * 3 + 1` overflows an `i64` after around 40 steps
+    let relation = Relation::new(BigInt::from(1), |x| x * 3 + 1);
+
+    for (index, term) in relation.enumerate().step_by(25).take(5) {
+        println!("t({index}) = {term}");
+    }
+}
@@ -0,0 +1,46 @@
+/* C API for the relation crate, built with the `ffi` feature */
+
+#ifndef RELATION_H
+#define RELATION_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An opaque handle to a relation of double terms, released with relation_free */
+typedef struct RelationHandle RelationHandle;
+
+/* Creates a relation where t(n+1) = multiplier * t(n) + increment */
+RelationHandle *relation_new_affine(double starter, double multiplier, double increment);
+
+/* Creates a relation for a balance earning compound interest, where V(n+1) = V(n) * (1 + rate) */
+RelationHandle *relation_new_compound_interest(double principal, double rate_per_period);
+
+/* Creates a relation for an ordinary annuity, where V(n+1) = V(n) * (1 + rate) + payment */
+RelationHandle *relation_new_annuity(double start, double rate, double payment);
+
+/* Creates a relation for an annuity due, where V(n+1) = (V(n) + payment) * (1 + rate) */
+RelationHandle *relation_new_annuity_due(double start, double rate, double payment);
+
+/* Returns the current number, or NaN if handle is null */
+double relation_current(const RelationHandle *handle);
+
+/* Returns the index of the current number, or 0 if handle is null */
+size_t relation_index(const RelationHandle *handle);
+
+/* Advances the relation by one step and returns the new current number, or NaN if handle is null */
+double relation_next(RelationHandle *handle);
+
+/* Advances the relation by index steps and returns the new current number, or NaN if handle is null */
+double relation_nth(RelationHandle *handle, size_t index);
+
+/* Releases a handle, doing nothing if handle is null. The handle must not be used again afterwards */
+void relation_free(RelationHandle *handle);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,369 @@
+//! Python bindings for the `relation` crate
+//!
+//! Build and install the module into the active environment with `maturin develop` from this directory,
+//! and then `import relation`
+
+use pyo3::prelude::*;
+
+/// A relation whose rule is a Python callable, taking the current term and returning the next one
+///
+/// Terms can be any Python objects the rule accepts, such as `float`, `int`, or `fractions.Fraction`
+#[pyclass(name = "Relation")]
+struct PyRelation {
+    rule: Py<PyAny>,
+    starter: Py<PyAny>,
+    current: Py<PyAny>,
+    index: usize,
+}
+
+#[pymethods]
+impl PyRelation {
+    /// Create a new relation from a starting term and a rule
+    #[new]
+    fn new(py: Python<'_>, starter: Py<PyAny>, rule: Py<PyAny>) -> Self {
+        Self {
+            current: starter.clone_ref(py),
+            starter,
+            rule,
+            index: 0,
+        }
+    }
+
+    /// The starting term
+    #[getter]
+    fn starter(&self, py: Python<'_>) -> Py<PyAny> {
+        self.starter.clone_ref(py)
+    }
+
+    /// The current term
+    #[getter]
+    fn current(&self, py: Python<'_>) -> Py<PyAny> {
+        self.current.clone_ref(py)
+    }
+
+    /// The index of the current term, where the starting term is at index `0`
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next term, without updating the relation
+    fn calculate_next(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.rule.call1(py, (self.current.clone_ref(py),))
+    }
+
+    /// Advances the relation by one step, and then returns the new current term
+    #[pyo3(name = "next")]
+    fn advance(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.current = self.calculate_next(py)?;
+        self.index += 1;
+
+        Ok(self.current(py))
+    }
+
+    /// Advances the relation by `index` steps, and then returns the new current term
+    fn nth(&mut self, py: Python<'_>, index: usize) -> PyResult<Py<PyAny>> {
+        for _ in 0..index {
+            self.advance(py)?;
+        }
+
+        Ok(self.current(py))
+    }
+
+    /// Returns the next `count` terms, beginning with the current term, without updating the relation
+    fn terms(&self, py: Python<'_>, count: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let mut terms = Vec::with_capacity(count.min(1024));
+
+        if count == 0 {
+            return Ok(terms);
+        }
+
+        let mut term = self.current(py);
+
+        for _ in 1..count {
+            let next = self.rule.call1(py, (term.clone_ref(py),))?;
+            terms.push(term);
+            term = next;
+        }
+
+        terms.push(term);
+
+        Ok(terms)
+    }
+
+    /// Resets the relation to its starting term
+    fn reset(&mut self, py: Python<'_>) {
+        self.current = self.starter(py);
+        self.index = 0;
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Yields the current term and then advances the relation, so iteration never ends by itself
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let current = self.current(py);
+        self.advance(py)?;
+
+        Ok(current)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "Relation(current={}, index={})",
+            self.current.bind(py).repr()?,
+            self.index
+        ))
+    }
+}
+
+/// A relation of floats where `t(n+1) = multiplier * t(n) + increment`, as made by the finance constructors
+#[pyclass(name = "AffineRelation")]
+struct PyAffineRelation {
+    relation: relation::AffineRelation<f64>,
+}
+
+#[pymethods]
+impl PyAffineRelation {
+    /// Create a new affine relation
+    #[new]
+    fn new(starter: f64, multiplier: f64, increment: f64) -> Self {
+        relation::AffineRelation::new(starter, multiplier, increment).into()
+    }
+
+    /// The multiplier of the current term
+    #[getter]
+    fn multiplier(&self) -> f64 {
+        *self.relation.multiplier()
+    }
+
+    /// The amount added after multiplying
+    #[getter]
+    fn increment(&self) -> f64 {
+        *self.relation.increment()
+    }
+
+    /// The current term
+    #[getter]
+    fn current(&self) -> f64 {
+        *self.relation.current()
+    }
+
+    /// The index of the current term, where the starting term is at index `0`
+    #[getter]
+    fn index(&self) -> usize {
+        self.relation.index()
+    }
+
+    /// Calculates and returns the next term, without updating the relation
+    fn calculate_next(&self) -> f64 {
+        self.relation.calculate_next()
+    }
+
+    /// Advances the relation by one step, and then returns the new current term
+    #[pyo3(name = "next")]
+    fn advance(&mut self) -> f64 {
+        self.relation.next();
+
+        self.current()
+    }
+
+    /// Advances the relation by `index` steps, and then returns the new current term
+    fn nth(&mut self, index: usize) -> f64 {
+        self.relation.nth(index)
+    }
+
+    /// Returns the term `index` steps after the current term in closed form, without updating the relation
+    fn nth_closed_form(&self, index: usize) -> f64 {
+        self.relation.nth_closed_form(index)
+    }
+
+    /// Returns the next `count` terms, beginning with the current term, without updating the relation
+    fn terms(&self, count: usize) -> Vec<f64> {
+        self.relation.take(count).collect()
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Yields the current term and then advances the relation, so iteration never ends by itself
+    fn __next__(&mut self) -> f64 {
+        let current = self.current();
+        self.relation.next();
+
+        current
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AffineRelation(current={}, multiplier={}, increment={}, index={})",
+            self.current(),
+            self.multiplier(),
+            self.increment(),
+            self.index()
+        )
+    }
+}
+
+impl From<relation::AffineRelation<f64>> for PyAffineRelation {
+    fn from(relation: relation::AffineRelation<f64>) -> Self {
+        Self { relation }
+    }
+}
+
+/// A balance earning compound interest, where `V(n+1) = V(n) * (1 + rate)`
+#[pyfunction]
+fn compound_interest(principal: f64, rate_per_period: f64) -> PyAffineRelation {
+    relation::finance::compound_interest(principal, rate_per_period).into()
+}
+
+/// An ordinary annuity, where `V(n+1) = V(n) * (1 + rate) + payment`
+#[pyfunction]
+fn annuity(start: f64, rate: f64, payment: f64) -> PyAffineRelation {
+    relation::finance::annuity(start, rate, payment).into()
+}
+
+/// An annuity due, where `V(n+1) = (V(n) + payment) * (1 + rate)`
+#[pyfunction]
+fn annuity_due(start: f64, rate: f64, payment: f64) -> PyAffineRelation {
+    relation::finance::annuity_due(start, rate, payment).into()
+}
+
+/// The book value of an asset under declining balance depreciation, where `V(n+1) = V(n) * (1 - rate)`
+#[pyfunction]
+fn declining_balance(cost: f64, rate: f64) -> PyAffineRelation {
+    relation::finance::declining_balance(cost, rate).into()
+}
+
+/// A parameter of a sweep, with the terms of the run using it
+type Trajectory = (Py<PyAny>, Vec<Py<PyAny>>);
+
+/// Runs `rule(param, term)` for each of `params` from `starter`, returning a list of `(param, trajectory)` pairs,
+/// where each trajectory holds the `steps + 1` terms from the starter to the final term
+#[pyfunction]
+fn sweep_trajectories(
+    py: Python<'_>,
+    rule: Py<PyAny>,
+    starter: Py<PyAny>,
+    params: Vec<Py<PyAny>>,
+    steps: usize,
+) -> PyResult<Vec<Trajectory>> {
+    params
+        .into_iter()
+        .map(|param| {
+            let mut term = starter.clone_ref(py);
+            let mut trajectory = Vec::with_capacity(steps.saturating_add(1).min(1024));
+
+            for _ in 0..steps {
+                let next = rule.call1(py, (param.clone_ref(py), term.clone_ref(py)))?;
+                trajectory.push(term);
+                term = next;
+            }
+
+            trajectory.push(term);
+
+            Ok((param, trajectory))
+        })
+        .collect()
+}
+
+/// Runs `rule(param, term)` for `steps` steps from `starter` with each of `params`,
+/// returning a list of `(param, final term)` pairs
+#[pyfunction]
+fn sweep(
+    py: Python<'_>,
+    rule: Py<PyAny>,
+    starter: Py<PyAny>,
+    params: Vec<Py<PyAny>>,
+    steps: usize,
+) -> PyResult<Vec<(Py<PyAny>, Py<PyAny>)>> {
+    params
+        .into_iter()
+        .map(|param| {
+            let mut term = starter.clone_ref(py);
+
+            for _ in 0..steps {
+                term = rule.call1(py, (param.clone_ref(py), term))?;
+            }
+
+            Ok((param, term))
+        })
+        .collect()
+}
+
+/// Recurrence relations, finance models, and parameter sweeps
+#[pymodule]
+#[pyo3(name = "relation")]
+fn relation_python(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyRelation>()?;
+    module.add_class::<PyAffineRelation>()?;
+    module.add_function(wrap_pyfunction!(compound_interest, module)?)?;
+    module.add_function(wrap_pyfunction!(annuity, module)?)?;
+    module.add_function(wrap_pyfunction!(annuity_due, module)?)?;
+    module.add_function(wrap_pyfunction!(declining_balance, module)?)?;
+    module.add_function(wrap_pyfunction!(sweep, module)?)?;
+    module.add_function(wrap_pyfunction!(sweep_trajectories, module)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::PyModule;
+
+    use super::*;
+
+    /// Runs `code` with the module imported as `relation`
+    fn run(code: &std::ffi::CStr) {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let module = PyModule::new(py, "relation").unwrap();
+            relation_python(&module).unwrap();
+
+            let globals = pyo3::types::PyDict::new(py);
+            globals.set_item("relation", module).unwrap();
+
+            py.run(code, Some(&globals), None).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_callable_relation() {
+        run(c"
+doubling = relation.Relation(1, lambda x: x * 2)
+assert doubling.terms(4) == [1, 2, 4, 8]
+assert doubling.nth(10) == 1024
+assert doubling.index == 10
+doubling.reset()
+assert [term for _, term in zip(range(3), doubling)] == [1, 2, 4]
+assert doubling.current == 8
+calls = []
+counted = relation.Relation(1, lambda x: calls.append(x) or x + 1)
+assert counted.terms(3) == [1, 2, 3] and calls == [1, 2]
+assert counted.terms(0) == [] and len(calls) == 2
+");
+    }
+
+    #[test]
+    fn test_finance_constructors() {
+        run(c"
+savings = relation.annuity(0.0, 0.05, 100.0)
+assert savings.terms(3) == [0.0, 100.0, 205.0]
+assert abs(savings.nth_closed_form(2) - 205.0) < 1e-9
+assert relation.compound_interest(100.0, 0.1).next() == 110.00000000000001
+");
+    }
+
+    #[test]
+    fn test_sweeps() {
+        run(c"
+logistic = lambda r, x: r * x * (1 - x)
+((r, last),) = relation.sweep(logistic, 0.5, [2.0], 100)
+assert r == 2.0 and abs(last - 0.5) < 1e-12
+assert relation.sweep_trajectories(lambda step, x: x + step, 0, [1, 2], 2) == [(1, [0, 1, 2]), (2, [0, 2, 4])]
+");
+    }
+}
@@ -0,0 +1,187 @@
+use num_traits::Float;
+
+use crate::Relation;
+
+/// A relation's terms transformed by Aitken's delta-squared process, which converges faster for linearly converging relations
+///
+/// Created by [`Relation::aitken`]. Each accelerated term is `t(n) - (t(n+1) - t(n))^2 / (t(n+2) - 2t(n+1) + t(n))`,
+/// or `t(n+2)` when the denominator is zero, as the relation has then stopped changing or is arithmetic
+#[derive(Debug, Copy, Clone)]
+pub struct Aitken<I, F> {
+    relation: Relation<I, F>,
+}
+
+/// A relation's terms transformed by Richardson extrapolation, which converges faster when the error shrinks like `n^-order`
+///
+/// Created by [`Relation::richardson`]. Each accelerated term is `((n+1)^p t(n+1) - n^p t(n)) / ((n+1)^p - n^p)`,
+/// which removes the leading error term, where `n` is the index of the term
+#[derive(Debug, Copy, Clone)]
+pub struct Richardson<I, F> {
+    relation: Relation<I, F>,
+    order: I,
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Accelerates the relation with Aitken's delta-squared process, beginning with the current number
+    ///
+    /// This suits fixed point iterations, whose error shrinks by a constant factor each step
+    pub fn aitken(self) -> Aitken<I, F> {
+        Aitken { relation: self }
+    }
+
+    /// Accelerates the relation with Richardson extrapolation, for an error which shrinks like `n^-order`
+    ///
+    /// This suits series and sequences such as `1 + 1/n`, which converge too slowly for [`Relation::aitken`]
+    pub fn richardson(self, order: I) -> Richardson<I, F> {
+        Richardson {
+            relation: self,
+            order,
+        }
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Aitken<I, F> {
+    /// Returns the accelerated term, from the current number and the two after it
+    pub fn current(&self) -> I {
+        let first = self.relation.current_number;
+        let second = (self.relation.relation)(first);
+        let third = (self.relation.relation)(second);
+
+        let denominator = third - second - second + first;
+
+        if denominator.is_zero() {
+            return third;
+        }
+
+        first - (second - first).powi(2) / denominator
+    }
+
+    /// Returns the index of the current number of the relation, which is the first of the terms the accelerated term uses
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Advances the relation by one step
+    pub fn next(&mut self) {
+        self.relation.next();
+    }
+
+    /// Calls [`Aitken::next`] `index` times, and then returns the accelerated term
+    pub fn nth(&mut self, index: usize) -> I {
+        self.relation.nth(index);
+
+        self.current()
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Richardson<I, F> {
+    /// Returns the accelerated term, from the current number and the one after it
+    pub fn current(&self) -> I {
+        let index = I::from(self.relation.index).unwrap_or_else(I::nan);
+        let weight = index.powf(self.order);
+        let next_weight = (index + I::one()).powf(self.order);
+
+        (next_weight * self.relation.calculate_next() - weight * self.relation.current_number)
+            / (next_weight - weight)
+    }
+
+    /// Returns the index of the current number of the relation, which is the first of the terms the accelerated term uses
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Advances the relation by one step
+    pub fn next(&mut self) {
+        self.relation.next();
+    }
+
+    /// Calls [`Richardson::next`] `index` times, and then returns the accelerated term
+    pub fn nth(&mut self, index: usize) -> I {
+        self.relation.nth(index);
+
+        self.current()
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Iterator for Aitken<I, F> {
+    type Item = I;
+
+    /// Yields the accelerated term and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        Aitken::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Iterator for Richardson<I, F> {
+    type Item = I;
+
+    /// Yields the accelerated term and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        Richardson::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aitken() {
+        // Converges linearly to the fixed point of cos, near 0.739085
+        let limit = 0.7390851332151607;
+        let relation = Relation::new(1., |x: f64| x.cos());
+
+        let mut plain = relation;
+        let mut accelerated = relation.aitken();
+
+        let plain_error = (plain.nth(10) - limit).abs();
+        let accelerated_error = (accelerated.nth(10) - limit).abs();
+
+        assert!(accelerated_error < plain_error / 1000.);
+
+        // A geometric error is removed exactly
+        let geometric = Relation::new(3., |x| (x - 2.) / 2. + 2.).aitken();
+
+        assert_eq!(geometric.take(1).next(), Some(2.));
+
+        // A constant relation has a zero denominator
+        assert_eq!(Relation::new(5., |x| x).aitken().current(), 5.);
+    }
+
+    #[test]
+    fn test_richardson() {
+        // The term at index n is 1 + 1/n, from index 1
+        let mut one_over_n = Relation::new(0., |x: f64| 1. / (1. / (x - 1.) + 1.) + 1.);
+        one_over_n.next();
+        one_over_n.set_current(2.);
+
+        let plain = one_over_n.nth_calculated(20);
+        let accelerated = one_over_n.richardson(1.).nth(19);
+
+        assert!((plain - 1.).abs() > 0.01);
+
+        assert!((accelerated - 1.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,238 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// A relation of the form `t(n+1) = a * t(n) + b`
+///
+/// Unlike [`Relation`](crate::Relation) the rule is stored as data rather than a closure,
+/// so it can be inspected, compared, and serialized
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AffineRelation<I> {
+    current: I,
+    index: usize,
+    multiplier: I,
+    increment: I,
+}
+
+impl<I: Num + Clone> AffineRelation<I> {
+    /// Create a new [`AffineRelation`] with the rule `t(n+1) = multiplier * t(n) + increment`
+    pub const fn new(starter: I, multiplier: I, increment: I) -> Self {
+        Self {
+            current: starter,
+            index: 0,
+            multiplier,
+            increment,
+        }
+    }
+
+    /// Create a new linear [`AffineRelation`] with the rule `t(n+1) = multiplier * t(n)`
+    pub fn linear(starter: I, multiplier: I) -> Self {
+        Self::new(starter, multiplier, I::zero())
+    }
+
+    /// Returns the multiplier `a` of the rule
+    pub const fn multiplier(&self) -> &I {
+        &self.multiplier
+    }
+
+    /// Returns the increment `b` of the rule
+    pub const fn increment(&self) -> &I {
+        &self.increment
+    }
+
+    /// Returns the current number
+    pub const fn current(&self) -> &I {
+        &self.current
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        self.multiplier.clone() * self.current.clone() + self.increment.clone()
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`AffineRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current.clone()
+    }
+
+    /// Calculates and returns the number `index` steps after the current number, without updating the struct
+    ///
+    /// Rather than stepping, this evaluates the closed form `a^n t + b (a^(n-1) + ... + a + 1)` exactly,
+    /// by repeatedly squaring the rule in `O(log n)` operations.
+    /// For an arithmetic sequence this is `t + n * d`, and for a geometric sequence `t * r^n`
+    pub fn nth_closed_form(&self, index: usize) -> I {
+        // The rule x -> a * x + b, raised to successive powers of two
+        let mut power = (self.multiplier.clone(), self.increment.clone());
+        // The rule x -> a * x + b, raised to the bits of `index` seen so far
+        let mut result = (I::one(), I::zero());
+        let mut index = index;
+
+        while index > 0 {
+            if index & 1 == 1 {
+                result = (
+                    power.0.clone() * result.0,
+                    power.0.clone() * result.1 + power.1.clone(),
+                );
+            }
+
+            index >>= 1;
+
+            if index > 0 {
+                power = (
+                    power.0.clone() * power.0.clone(),
+                    power.0.clone() * power.1.clone() + power.1,
+                );
+            }
+        }
+
+        result.0 * self.current.clone() + result.1
+    }
+}
+
+macro_rules! impl_const_affine {
+    ($($int:ty),*) => {
+        $(
+            impl AffineRelation<$int> {
+                /// Calculates and returns the number `index` steps after the current number, without updating the struct
+                ///
+                /// Unlike [`AffineRelation::nth`] this is a `const fn`, so lookup tables of terms can be built at compile time.
+                /// An overflow is a compile error in a `const` context
+                pub const fn nth_const(&self, index: usize) -> $int {
+                    let mut current = self.current;
+                    let mut step = 0;
+
+                    while step < index {
+                        current = self.multiplier * current + self.increment;
+                        step += 1;
+                    }
+
+                    current
+                }
+            }
+        )*
+    };
+}
+
+impl_const_affine!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<I: Num + Clone> Relation<I, ()> {
+    /// Create a new arithmetic [`AffineRelation`], where `t(n+1) = t(n) + difference`
+    pub fn arithmetic(starter: I, difference: I) -> AffineRelation<I> {
+        AffineRelation::new(starter, I::one(), difference)
+    }
+
+    /// Create a new geometric [`AffineRelation`], where `t(n+1) = t(n) * ratio`
+    pub fn geometric(starter: I, ratio: I) -> AffineRelation<I> {
+        AffineRelation::linear(starter, ratio)
+    }
+}
+
+impl<I: Num + Clone> Iterator for AffineRelation<I> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.clone();
+        AffineRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affine_relation() {
+        let mut relation = AffineRelation::new(5, 2, 3);
+
+        assert_eq!(relation.calculate_next(), 13);
+
+        relation.next();
+
+        assert_eq!(relation.current, 13);
+
+        assert_eq!(relation.index(), 1);
+
+        assert_eq!(relation.nth(3), 125);
+    }
+
+    #[test]
+    fn test_arithmetic_and_geometric() {
+        let arithmetic = Relation::arithmetic(3, 4);
+
+        assert_eq!(arithmetic.nth_closed_form(1000), 4003);
+
+        assert!(arithmetic.take(4).eq([3, 7, 11, 15]));
+
+        let geometric = Relation::geometric(3u64, 2);
+
+        assert_eq!(geometric.nth_closed_form(40), 3 << 40);
+
+        assert!(geometric.take(4).eq([3, 6, 12, 24]));
+    }
+
+    #[test]
+    fn test_closed_form_matches_stepping() {
+        let mut relation = AffineRelation::new(5i64, -2, 3);
+
+        for n in 0..20 {
+            assert_eq!(relation.nth_closed_form(n), relation.clone().nth(n));
+        }
+
+        relation.nth(3);
+
+        assert_eq!(relation.nth_closed_form(5), relation.nth(5));
+    }
+
+    #[test]
+    fn test_linear_relation() {
+        let relation = AffineRelation::linear(1., 0.5);
+
+        assert_eq!(relation.increment(), &0.);
+
+        assert!(relation.take(4).eq([1., 0.5, 0.25, 0.125]));
+    }
+
+    #[test]
+    fn test_nth_const() {
+        const POWERS: [u32; 5] = {
+            let relation = AffineRelation::new(1u32, 3, 0);
+            let mut powers = [0; 5];
+            let mut index = 0;
+
+            while index < powers.len() {
+                powers[index] = relation.nth_const(index);
+                index += 1;
+            }
+
+            powers
+        };
+
+        assert_eq!(POWERS, [1, 3, 9, 27, 81]);
+
+        let relation = AffineRelation::new(5i64, 2, 3);
+
+        assert_eq!(relation.nth_const(3), relation.clone().nth(3));
+    }
+}
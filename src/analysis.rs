@@ -0,0 +1,213 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::{Float, Num};
+
+use crate::{abs_diff, Relation};
+
+/// The kind of progression a sample of terms follows
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Progression<I> {
+    /// Each term is the previous term plus a common difference
+    Arithmetic {
+        /// The common difference
+        difference: I,
+    },
+    /// Each term is the previous term times a common ratio
+    Geometric {
+        /// The common ratio
+        ratio: I,
+    },
+    /// The terms follow neither an arithmetic nor a geometric progression,
+    /// or there were fewer than two terms to classify
+    Neither,
+}
+
+/// Classifies a sample of terms as an arithmetic progression, a geometric progression, or neither
+///
+/// Terms are compared within `tolerance`, which should be zero for integer types.
+/// A constant sample is classified as arithmetic, with a difference of zero
+pub fn classify<I: Num + Clone + PartialOrd>(
+    terms: impl IntoIterator<Item = I>,
+    tolerance: I,
+) -> Progression<I> {
+    let mut terms = terms.into_iter();
+
+    let (Some(first), Some(second)) = (terms.next(), terms.next()) else {
+        return Progression::Neither;
+    };
+
+    let difference = second.clone() - first.clone();
+    let ratio = (!first.is_zero()).then(|| second.clone() / first.clone());
+
+    let mut is_arithmetic = true;
+    let mut is_geometric = ratio
+        .as_ref()
+        .is_some_and(|ratio| abs_diff(&(first * ratio.clone()), &second) <= tolerance);
+
+    let mut previous = second;
+    for term in terms {
+        is_arithmetic =
+            is_arithmetic && abs_diff(&(previous.clone() + difference.clone()), &term) <= tolerance;
+
+        is_geometric = is_geometric
+            && ratio.as_ref().is_some_and(|ratio| {
+                abs_diff(&(previous.clone() * ratio.clone()), &term) <= tolerance
+            });
+
+        if !is_arithmetic && !is_geometric {
+            return Progression::Neither;
+        }
+
+        previous = term;
+    }
+
+    match ratio {
+        _ if is_arithmetic => Progression::Arithmetic { difference },
+        Some(ratio) if is_geometric => Progression::Geometric { ratio },
+        _ => Progression::Neither,
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Classifies the next `count` terms, beginning with the current number, without updating the struct
+    ///
+    /// See [`classify`] for how the terms are classified
+    pub fn classify(&self, count: usize, tolerance: I) -> Progression<I> {
+        classify(self.clone().take(count), tolerance)
+    }
+}
+
+/// Calculates the sample autocorrelation of a window of terms at `lag`
+///
+/// This is the correlation of the terms with themselves `lag` steps later, between `-1` and `1`,
+/// where values near zero mean the terms have no structure at that lag.
+/// Returns [`None`] if `lag` is not shorter than the window, or the terms are constant
+pub fn autocorrelation<I: Float>(terms: &[I], lag: usize) -> Option<I> {
+    if lag >= terms.len() {
+        return None;
+    }
+
+    let count = I::from(terms.len())?;
+    let mean = terms.iter().fold(I::zero(), |sum, &term| sum + term) / count;
+
+    let variance = terms
+        .iter()
+        .fold(I::zero(), |sum, &term| sum + (term - mean).powi(2));
+
+    if variance.is_zero() {
+        return None;
+    }
+
+    let covariance = terms
+        .iter()
+        .zip(&terms[lag..])
+        .fold(I::zero(), |sum, (&term, &later)| {
+            sum + (term - mean) * (later - mean)
+        });
+
+    Some(covariance / variance)
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calculates the autocorrelation at each of `lags`, over `count` terms beginning with the current number,
+    /// without updating the struct
+    ///
+    /// See [`autocorrelation`] for how each lag is calculated
+    pub fn autocorrelation(&self, count: usize, lags: &[usize]) -> Vec<Option<I>> {
+        let terms = self.terms(count);
+
+        lags.iter()
+            .map(|&lag| autocorrelation(&terms, lag))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_slices() {
+        assert_eq!(
+            classify([3, 7, 11, 15], 0),
+            Progression::Arithmetic { difference: 4 }
+        );
+
+        assert_eq!(
+            classify([3, 6, 12, 24], 0),
+            Progression::Geometric { ratio: 2 }
+        );
+
+        assert_eq!(classify([1, 2, 4, 7], 0), Progression::Neither);
+
+        assert_eq!(classify([4, 6, 9], 0), Progression::Neither);
+
+        assert_eq!(
+            classify([5, 5, 5], 0),
+            Progression::Arithmetic { difference: 0 }
+        );
+
+        assert_eq!(classify([1], 0), Progression::Neither);
+    }
+
+    #[test]
+    fn test_classify_floats() {
+        assert_eq!(
+            classify([0.1, 0.2, 0.30000000000000004, 0.4], 1e-12),
+            Progression::Arithmetic { difference: 0.1 }
+        );
+
+        assert_eq!(
+            classify([4., 6., 9., 13.5], 1e-12),
+            Progression::Geometric { ratio: 1.5 }
+        );
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        let alternating = [1., -1., 1., -1., 1., -1.];
+
+        assert_eq!(autocorrelation(&alternating, 0), Some(1.));
+
+        assert!((autocorrelation(&alternating, 1).unwrap() + 5. / 6.).abs() < 1e-12);
+
+        assert_eq!(autocorrelation(&alternating, 6), None);
+
+        assert_eq!(autocorrelation(&[2., 2., 2.], 1), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_relation_autocorrelation() {
+        // A period two cycle is perfectly correlated every second step
+        let cycle = Relation::new(0.2, |x: f64| if x < 0.5 { 0.8 } else { 0.2 });
+
+        let correlations = cycle.autocorrelation(1000, &[1, 2]);
+
+        assert!((correlations[0].unwrap() + 1.).abs() < 0.01);
+
+        assert!((correlations[1].unwrap() - 1.).abs() < 0.01);
+
+        // The chaotic logistic map is close to uncorrelated with its next term
+        let logistic = Relation::new(0.1, |x: f64| 4. * x * (1. - x));
+
+        assert!(logistic.autocorrelation(10_000, &[1])[0].unwrap().abs() < 0.05);
+    }
+
+    #[test]
+    fn test_classify_relation() {
+        let relation = Relation::new(1000., |x: f64| x * 1.05);
+
+        let Progression::Geometric { ratio } = relation.classify(50, 1e-6) else {
+            panic!("compound interest is geometric");
+        };
+
+        assert!((ratio - 1.05).abs() < 1e-12);
+
+        let relation = Relation::new(1, |x| x * 2 + 1);
+
+        assert_eq!(relation.classify(10, 0), Progression::Neither);
+    }
+}
@@ -0,0 +1,172 @@
+use ndarray::Array1;
+use num_traits::Num;
+
+use crate::{abs_diff, Convergence, Error, Relation};
+
+/// A relation applied elementwise to a whole batch of independent starting points
+///
+/// Created by [`Relation::batch`], or with [`BatchRelation::new`].
+/// The batch is stored as an [`Array1`], and every element is advanced by the same rule on each step,
+/// which suits large ensembles of starting points
+#[derive(Debug, Clone)]
+pub struct BatchRelation<I, F> {
+    current: Array1<I>,
+    index: usize,
+    relation: F,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Applies the rule of the relation to a batch of starting points, instead of its current number
+    pub fn batch(&self, starters: Array1<I>) -> BatchRelation<I, F> {
+        BatchRelation::new(starters, self.relation)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> BatchRelation<I, F> {
+    /// Create a new [`BatchRelation`]
+    pub fn new(starters: Array1<I>, relation: F) -> Self {
+        Self {
+            current: starters,
+            index: 0,
+            relation,
+        }
+    }
+
+    /// Returns the current batch
+    pub fn current(&self) -> &Array1<I> {
+        &self.current
+    }
+
+    /// Returns the number of starting points in the batch
+    pub fn len(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Returns whether the batch has no starting points
+    pub fn is_empty(&self) -> bool {
+        self.current.is_empty()
+    }
+
+    /// Returns the index of the current batch, where the starters are at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next batch
+    pub fn calculate_next(&self) -> Array1<I> {
+        self.current.mapv(|x| (self.relation)(x))
+    }
+
+    /// Calculates the next batch and updates the struct, without allocating
+    pub fn next(&mut self) {
+        self.current.mapv_inplace(|x| (self.relation)(x));
+        self.index += 1;
+    }
+
+    /// Calls [`BatchRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> Array1<I> {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current.clone()
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy> BatchRelation<I, F> {
+    /// Calls [`BatchRelation::next`] until every element differs from its previous value by less than `tolerance`
+    ///
+    /// Returns the last batch as the estimated limits, along with the number of steps taken
+    ///
+    /// # Errors
+    /// - If some element is still not within `tolerance` of its previous value after `max_steps` steps
+    pub fn converge(
+        &mut self,
+        tolerance: I,
+        max_steps: usize,
+    ) -> Result<Convergence<Array1<I>>, Error> {
+        for steps in 1..=max_steps {
+            let previous = self.current.clone();
+            self.next();
+
+            let converged = self
+                .current
+                .iter()
+                .zip(&previous)
+                .all(|(current, previous)| abs_diff(current, previous) < tolerance);
+
+            if converged {
+                return Ok(Convergence {
+                    limit: self.current.clone(),
+                    steps,
+                });
+            }
+        }
+
+        Err(Error::DidNotConverge { steps: max_steps })
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for BatchRelation<I, F> {
+    type Item = Array1<I>;
+
+    /// Yields the current batch and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.clone();
+        BatchRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn test_batch_relation() {
+        let mut batch = Relation::new(0, |x| x * 2 + 1).batch(array![0, 1, 2]);
+
+        assert_eq!(batch.len(), 3);
+
+        assert_eq!(batch.calculate_next(), array![1, 3, 5]);
+
+        assert_eq!(batch.nth(2), array![3, 7, 11]);
+
+        assert_eq!(batch.index(), 2);
+
+        let halving = BatchRelation::new(array![8, 4], |x| x / 2);
+
+        assert!(halving
+            .take(3)
+            .eq([array![8, 4], array![4, 2], array![2, 1]]));
+    }
+
+    #[test]
+    fn test_batch_convergence() {
+        // Every positive starting point converges to the square root of two
+        let starters = Array1::from_iter((0..1000).map(|i| 0.5 + 0.1 * f64::from(i)));
+        let mut batch = BatchRelation::new(starters, |x: f64| (x + 2. / x) / 2.);
+
+        let convergence = batch.converge(1e-12, 100).unwrap();
+
+        assert!(convergence
+            .limit
+            .iter()
+            .all(|&limit| (limit - 2f64.sqrt()).abs() < 1e-12));
+
+        let mut diverging = BatchRelation::new(array![1., 0.], |x: f64| x * 2.);
+
+        assert_eq!(
+            diverging.converge(1e-12, 10),
+            Err(Error::DidNotConverge { steps: 10 })
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Prints the terms of a relation whose rule is given as an expression, such as
+//!
+//! ```sh
+//! relation "1.08*x + 100" --start 0 --steps 30 --format csv
+//! ```
+
+use std::{
+    env,
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use relation::ExpressionRelation;
+
+const USAGE: &str = "\
+Usage: relation <RULE> [OPTIONS]
+
+The rule is an expression in the current term `x` and its index `n`, such as `1.08*x + 100`
+
+Options:
+  --start <NUMBER>      The starting term [default: 0]
+  --steps <COUNT>       The number of steps to take after the starting term, at most 100000 for a table [default: 10]
+  --format <FORMAT>     One of plain, table, csv or json [default: plain]
+  --precision <DIGITS>  The number of decimal places to write in a table
+  -h, --help            Print this message";
+
+/// The most steps a table can show, as its terms are all held in memory to align the columns
+const MAX_TABLE_STEPS: usize = 100_000;
+
+/// How the terms are written
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Format {
+    Plain,
+    Table,
+    Csv,
+    Json,
+}
+
+/// The parsed command line arguments
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    rule: String,
+    start: f64,
+    steps: usize,
+    format: Format,
+    precision: Option<usize>,
+}
+
+/// Parses the arguments after the program name, returning [`None`] if help was requested
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut args = args.into_iter();
+
+    let mut rule = None;
+    let mut start = 0.;
+    let mut steps = 10;
+    let mut format = Format::Plain;
+    let mut precision = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--start" => {
+                start = value()?
+                    .parse()
+                    .map_err(|_| String::from("--start should be a number"))?;
+            }
+            "--steps" => {
+                steps = value()?
+                    .parse()
+                    .map_err(|_| String::from("--steps should be a whole number"))?;
+            }
+            "--precision" => {
+                precision = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| String::from("--precision should be a whole number"))?,
+                );
+            }
+            "--format" => {
+                format = match value()?.as_str() {
+                    "plain" => Format::Plain,
+                    "table" => Format::Table,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format `{other}`")),
+                };
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+            _ if rule.is_none() => rule = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let rule = rule.ok_or_else(|| String::from("a rule is required"))?;
+
+    if format == Format::Table && steps > MAX_TABLE_STEPS {
+        return Err(format!(
+            "a table can show at most {MAX_TABLE_STEPS} steps, use another format for more"
+        ));
+    }
+
+    Ok(Some(Options {
+        rule,
+        start,
+        steps,
+        format,
+        precision,
+    }))
+}
+
+/// Writes the terms of `relation` from the starting term to the last step
+fn write_terms(
+    mut writer: impl Write,
+    relation: &ExpressionRelation<f64>,
+    options: &Options,
+) -> io::Result<()> {
+    let count = options.steps.saturating_add(1);
+
+    match options.format {
+        Format::Plain => {
+            for term in relation.clone().take(count) {
+                writeln!(writer, "{term}")?;
+            }
+
+            writer.flush()
+        }
+        Format::Table => {
+            let table = relation.table(count);
+
+            match options.precision {
+                Some(precision) => write!(writer, "{}", table.precision(precision))?,
+                None => write!(writer, "{table}")?,
+            }
+
+            writer.flush()
+        }
+        Format::Csv => relation.export_csv(writer, count),
+        #[cfg(feature = "json")]
+        Format::Json => relation.export_json_lines(writer, count),
+        #[cfg(not(feature = "json"))]
+        Format::Json => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "the json format needs the `json` feature",
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let relation = match ExpressionRelation::parse(&options.rule, options.start) {
+        Ok(relation) => relation,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match write_terms(io::stdout().lock(), &relation, &options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| String::from(arg)).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(args(&[
+            "1.08*x + 100",
+            "--start",
+            "0",
+            "--steps",
+            "30",
+            "--format",
+            "csv",
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            options,
+            Options {
+                rule: String::from("1.08*x + 100"),
+                start: 0.,
+                steps: 30,
+                format: Format::Csv,
+                precision: None,
+            }
+        );
+
+        assert_eq!(parse_args(args(&["x", "--help"])), Ok(None));
+
+        assert!(parse_args(args(&[])).is_err());
+
+        assert!(parse_args(args(&["x", "--steps"])).is_err());
+
+        assert!(parse_args(args(&["x", "--format", "xml"])).is_err());
+
+        assert!(parse_args(args(&["x", "y"])).is_err());
+
+        assert!(parse_args(args(&[
+            "x",
+            "--steps",
+            "18446744073709551615",
+            "--format",
+            "table"
+        ]))
+        .is_err());
+
+        assert!(parse_args(args(&[
+            "x",
+            "--steps",
+            "18446744073709551615",
+            "--format",
+            "csv"
+        ]))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_write_terms() {
+        let mut options = parse_args(args(&["2*x + 1", "--start", "1", "--steps", "2"]))
+            .unwrap()
+            .unwrap();
+        let relation = ExpressionRelation::parse(&options.rule, options.start).unwrap();
+
+        let mut output = Vec::new();
+        write_terms(&mut output, &relation, &options).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "1\n3\n7\n");
+
+        options.format = Format::Csv;
+
+        let mut output = Vec::new();
+        write_terms(&mut output, &relation, &options).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "index,value\n0,1\n1,3\n2,7\n"
+        );
+    }
+}
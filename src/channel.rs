@@ -0,0 +1,109 @@
+use std::{
+    sync::mpsc::{Sender, SyncSender},
+    thread::{self, JoinHandle},
+};
+
+use num_traits::Num;
+
+use crate::Relation;
+
+/// The sending half of a channel, which terms can be pushed into from a worker thread
+///
+/// Implemented for the senders of [`std::sync::mpsc`], and of `crossbeam-channel` with the `crossbeam` feature.
+/// A bounded channel gives backpressure, as sending blocks the worker until the receiver catches up
+pub trait TermSender<I>: Send {
+    /// Sends a term, returning `false` if the receiver has been dropped
+    fn send_term(&self, term: I) -> bool;
+}
+
+impl<I: Send> TermSender<I> for Sender<I> {
+    fn send_term(&self, term: I) -> bool {
+        self.send(term).is_ok()
+    }
+}
+
+impl<I: Send> TermSender<I> for SyncSender<I> {
+    fn send_term(&self, term: I) -> bool {
+        self.send(term).is_ok()
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<I: Send> TermSender<I> for crossbeam_channel::Sender<I> {
+    fn send_term(&self, term: I) -> bool {
+        self.send(term).is_ok()
+    }
+}
+
+impl<I: Num + Clone + Send + 'static, F: FnOnce(I) -> I + Copy + Send + 'static> Relation<I, F> {
+    /// Spawns a worker thread which calculates the next `count` terms, beginning with the current number,
+    /// and pushes each into `sender` as soon as it is calculated
+    ///
+    /// The worker stops early if the receiver is dropped.
+    /// Joining the returned handle gives the relation after the last term sent, so the run can be resumed
+    pub fn stream_into(
+        self,
+        sender: impl TermSender<I> + 'static,
+        count: usize,
+    ) -> JoinHandle<Relation<I, F>> {
+        thread::spawn(move || {
+            let mut relation = self;
+
+            for _ in 0..count {
+                if !sender.send_term(relation.current_number.clone()) {
+                    break;
+                }
+
+                relation.next();
+            }
+
+            relation
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn test_stream_into() {
+        let (sender, receiver) = mpsc::channel();
+
+        let worker = Relation::new(1u64, |x| x * 3).stream_into(sender, 5);
+
+        assert_eq!(receiver.iter().collect::<Vec<_>>(), [1, 3, 9, 27, 81]);
+
+        assert_eq!(worker.join().unwrap().current(), &243);
+    }
+
+    #[test]
+    fn test_backpressure_and_disconnect() {
+        // With no room in the channel, each term waits for the receiver before the next is calculated
+        let (sender, receiver) = mpsc::sync_channel(0);
+
+        let worker = Relation::new(0, |x| x + 1).stream_into(sender, 1_000_000);
+
+        let received: Vec<i32> = receiver.iter().take(3).collect();
+
+        assert_eq!(received, [0, 1, 2]);
+
+        drop(receiver);
+
+        assert!(worker.join().unwrap().index() < 10);
+    }
+
+    #[cfg(feature = "crossbeam")]
+    #[test]
+    fn test_crossbeam() {
+        let (sender, receiver) = crossbeam_channel::bounded(4);
+
+        let worker = Relation::new(2., |x: f64| x * x).stream_into(sender, 4);
+
+        assert_eq!(receiver.iter().collect::<Vec<_>>(), [2., 4., 16., 256.]);
+
+        assert_eq!(worker.join().unwrap().index(), 4);
+    }
+}
@@ -0,0 +1,131 @@
+use num_traits::Num;
+
+use crate::{trace, Error, Relation};
+
+/// A relation whose relation function can fail, such as from integer overflow
+///
+/// The relation function returns [`None`] when the next number cannot be calculated,
+/// which is reported as [`Error::Overflow`] rather than wrapping or panicking.
+/// This pairs naturally with the `checked_*` integer methods, and the [`num_traits::CheckedAdd`] family of traits
+#[derive(Debug, Copy, Clone)]
+pub struct CheckedRelation<I, F> {
+    current_number: I,
+    index: usize,
+    relation: F,
+    exhausted: bool,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> Option<I> + Copy> CheckedRelation<I, F> {
+    /// Create a new [`CheckedRelation`]
+    pub fn new(starter: I, relation: F) -> Self {
+        Self {
+            current_number: starter,
+            index: 0,
+            relation,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    ///
+    /// # Errors
+    /// - If the relation function could not calculate the next number
+    pub fn calculate_next(&self) -> Result<I, Error> {
+        (self.relation)(self.current_number.clone()).ok_or_else(|| {
+            trace::event!(WARN, index = self.index + 1, "relation overflowed");
+
+            Error::Overflow {
+                index: self.index + 1,
+            }
+        })
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    ///
+    /// # Errors
+    /// - If the relation function could not calculate the next number, in which case the struct is not updated
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<(), Error> {
+        self.current_number = self.calculate_next()?;
+        self.index += 1;
+
+        Ok(())
+    }
+
+    /// Calls [`CheckedRelation::next`] `index` times, and then returns the result
+    ///
+    /// # Errors
+    /// - If any of the numbers could not be calculated, in which case the struct is left at the last number that could be
+    pub fn nth(&mut self, index: usize) -> Result<I, Error> {
+        for _ in 0..index {
+            self.next()?;
+        }
+
+        Ok(self.current_number.clone())
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> Option<I> + Copy> Iterator for CheckedRelation<I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    ///
+    /// Ends after the last number that could be calculated
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let current = self.current_number.clone();
+        self.exhausted = CheckedRelation::next(self).is_err();
+
+        Some(current)
+    }
+}
+
+impl<I: Num + Clone> Relation<I, ()> {
+    /// Create a new [`CheckedRelation`], whose relation function returns [`None`] on overflow
+    pub fn checked<F: FnOnce(I) -> Option<I> + Copy>(
+        starter: I,
+        relation: F,
+    ) -> CheckedRelation<I, F> {
+        CheckedRelation::new(starter, relation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_relation() {
+        let mut relation = Relation::checked(1i64, |x: i64| x.checked_mul(3)?.checked_add(1));
+
+        assert_eq!(relation.calculate_next(), Ok(4));
+
+        assert_eq!(relation.nth(10), Ok(88573));
+
+        assert_eq!(relation.nth(100), Err(Error::Overflow { index: 40 }));
+
+        assert_eq!(relation.index(), 39);
+
+        assert_eq!(relation.current(), &6_078_832_729_528_464_400);
+    }
+
+    #[test]
+    fn test_checked_iterator() {
+        let relation = CheckedRelation::new(1u8, |x: u8| x.checked_mul(2));
+
+        assert!(relation.eq([1, 2, 4, 8, 16, 32, 64, 128]));
+    }
+}
@@ -0,0 +1,232 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::{Error, LinearRecurrence};
+
+/// The closed form of a linear recurrence of order one or two, found from the roots of its characteristic polynomial
+///
+/// Each variant gives the term `n` steps after the current number of the recurrence it was solved from
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClosedForm<I> {
+    /// `t(n) = a * r^n`, for a recurrence of order one
+    Geometric {
+        /// The coefficient of the root
+        a: I,
+        /// The root
+        r: I,
+    },
+    /// `t(n) = a * r1^n + b * r2^n`, for distinct real roots
+    DistinctRoots {
+        /// The coefficient of the first root
+        a: I,
+        /// The first root
+        r1: I,
+        /// The coefficient of the second root
+        b: I,
+        /// The second root
+        r2: I,
+    },
+    /// `t(n) = (a + b * n) * r^n`, for a repeated real root
+    RepeatedRoot {
+        /// The constant coefficient
+        a: I,
+        /// The coefficient of `n`
+        b: I,
+        /// The repeated root
+        r: I,
+    },
+    /// `t(n) = m^n * (a * cos(n * θ) + b * sin(n * θ))`, for a pair of complex conjugate roots `m * e^(±iθ)`
+    ComplexRoots {
+        /// The coefficient of the cosine
+        a: I,
+        /// The coefficient of the sine
+        b: I,
+        /// The modulus `m` of the roots
+        modulus: I,
+        /// The argument `θ` of the roots
+        argument: I,
+    },
+}
+
+/// Raises `base` to an integer power, which unlike [`Float::powf`] is well defined for negative bases
+pub(crate) fn power<I: Float>(base: I, exponent: usize) -> I {
+    match i32::try_from(exponent) {
+        Ok(exponent) => base.powi(exponent),
+        Err(_) => base.powf(I::from(exponent).unwrap_or_else(I::infinity)),
+    }
+}
+
+impl<I: Float> ClosedForm<I> {
+    /// Evaluates the closed form for the term `n` steps after the current number
+    pub fn evaluate(&self, n: usize) -> I {
+        let n_float = I::from(n).unwrap_or_else(I::infinity);
+
+        match *self {
+            ClosedForm::Geometric { a, r } => a * power(r, n),
+            ClosedForm::DistinctRoots { a, r1, b, r2 } => a * power(r1, n) + b * power(r2, n),
+            ClosedForm::RepeatedRoot { a, b, r } => (a + b * n_float) * power(r, n),
+            ClosedForm::ComplexRoots {
+                a,
+                b,
+                modulus,
+                argument,
+            } => {
+                let angle = n_float * argument;
+
+                power(modulus, n) * (a * angle.cos() + b * angle.sin())
+            }
+        }
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for ClosedForm<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClosedForm::Geometric { a, r } => write!(f, "{a} * ({r})^n"),
+            ClosedForm::DistinctRoots { a, r1, b, r2 } => {
+                write!(f, "{a} * ({r1})^n + {b} * ({r2})^n")
+            }
+            ClosedForm::RepeatedRoot { a, b, r } => write!(f, "({a} + {b} * n) * ({r})^n"),
+            ClosedForm::ComplexRoots {
+                a,
+                b,
+                modulus,
+                argument,
+            } => write!(
+                f,
+                "({modulus})^n * ({a} * cos({argument} * n) + {b} * sin({argument} * n))"
+            ),
+        }
+    }
+}
+
+impl<I: Float> LinearRecurrence<I> {
+    /// Solves the recurrence for its closed form, using the roots of its characteristic polynomial
+    ///
+    /// The closed form is relative to the current number, so `closed_form.evaluate(n)` matches `nth_calculated(n)`
+    ///
+    /// # Errors
+    /// - If the order is greater than two, as the roots are only solved for the linear and quadratic cases
+    /// - If the oldest coefficient is zero, as the recurrence is then degenerate
+    pub fn closed_form(&self) -> Result<ClosedForm<I>, Error> {
+        let two = I::one() + I::one();
+
+        match (self.coefficients(), self.terms()) {
+            (&[r], &[a]) => Ok(ClosedForm::Geometric { a, r }),
+            (&[c1, c2], &[t0, t1]) if !c2.is_zero() => {
+                // The characteristic polynomial is r^2 - c1 * r - c2
+                let discriminant = c1 * c1 + two * two * c2;
+                let centre = c1 / two;
+
+                if discriminant.abs() <= I::epsilon() * (c1 * c1).max(c2.abs()) {
+                    let r = centre;
+
+                    Ok(ClosedForm::RepeatedRoot {
+                        a: t0,
+                        b: t1 / r - t0,
+                        r,
+                    })
+                } else if discriminant > I::zero() {
+                    let offset = discriminant.sqrt() / two;
+                    let (r1, r2) = (centre + offset, centre - offset);
+                    let b = (t1 - r1 * t0) / (r2 - r1);
+
+                    Ok(ClosedForm::DistinctRoots {
+                        a: t0 - b,
+                        r1,
+                        b,
+                        r2,
+                    })
+                } else {
+                    let modulus = (-c2).sqrt();
+                    let argument = ((-discriminant).sqrt() / two).atan2(centre);
+                    let b = (t1 / modulus - t0 * argument.cos()) / argument.sin();
+
+                    Ok(ClosedForm::ComplexRoots {
+                        a: t0,
+                        b,
+                        modulus,
+                        argument,
+                    })
+                }
+            }
+            _ => Err(Error::NoClosedForm),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Checks `evaluate(n)` is within a relative `1e-9` of the `n`th of `terms`, reporting `closed_form` if not
+    pub(crate) fn assert_closed_form_matches(
+        closed_form: &impl fmt::Debug,
+        evaluate: impl Fn(usize) -> f64,
+        terms: impl Iterator<Item = f64>,
+    ) {
+        for (n, term) in terms.enumerate() {
+            let error = (evaluate(n) - term).abs();
+
+            assert!(error <= 1e-9 * term.abs().max(1.), "{closed_form:?} at {n}");
+        }
+    }
+
+    fn assert_matches_iteration(recurrence: LinearRecurrence<f64>) {
+        let closed_form = recurrence.closed_form().unwrap();
+
+        assert_closed_form_matches(
+            &closed_form,
+            |n| closed_form.evaluate(n),
+            recurrence.take(40),
+        );
+    }
+
+    #[test]
+    fn test_fibonacci_closed_form() {
+        let fibonacci = LinearRecurrence::new([1., 1.], [0., 1.]);
+
+        let ClosedForm::DistinctRoots { a, r1, b, r2 } = fibonacci.closed_form().unwrap() else {
+            panic!("the fibonacci sequence has distinct roots");
+        };
+
+        let sqrt_5 = 5f64.sqrt();
+
+        assert!((r1 - (1. + sqrt_5) / 2.).abs() < 1e-12);
+
+        assert!((r2 - (1. - sqrt_5) / 2.).abs() < 1e-12);
+
+        assert!((a - 1. / sqrt_5).abs() < 1e-12);
+
+        assert!((b + 1. / sqrt_5).abs() < 1e-12);
+
+        assert_matches_iteration(fibonacci);
+    }
+
+    #[test]
+    fn test_all_root_cases() {
+        // Geometric
+        assert_matches_iteration(LinearRecurrence::new([1.5], [2.]));
+
+        // Repeated root of 2
+        assert_matches_iteration(LinearRecurrence::new([4., -4.], [1., 3.]));
+
+        // Complex roots of ±i
+        assert_matches_iteration(LinearRecurrence::new([0., -1.], [1., 2.]));
+
+        // Complex roots with a modulus of √2
+        assert_matches_iteration(LinearRecurrence::new([2., -2.], [1., 0.]));
+    }
+
+    #[test]
+    fn test_no_closed_form() {
+        let tribonacci = LinearRecurrence::new([1., 1., 1.], [0., 0., 1.]);
+
+        assert_eq!(tribonacci.closed_form(), Err(Error::NoClosedForm));
+
+        let degenerate = LinearRecurrence::new([2., 0.], [1., 1.]);
+
+        assert_eq!(degenerate.closed_form(), Err(Error::NoClosedForm));
+    }
+}
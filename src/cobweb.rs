@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::Relation;
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Returns the corners of a cobweb diagram of `steps` steps from the current number, without updating the struct
+    ///
+    /// Joining the points in order draws the diagram. It begins at `(t(0), 0)` on the x axis,
+    /// then each step goes vertically to the curve at `(t(n), t(n+1))` and horizontally to the diagonal at `(t(n+1), t(n+1))`
+    pub fn cobweb(&self, steps: usize) -> Vec<(I, I)> {
+        // The number of steps may be far more than fits in memory, so only a little is reserved up front
+        let mut points = Vec::with_capacity(steps.saturating_mul(2).saturating_add(1).min(1024));
+        let mut x = self.current_number.clone();
+
+        points.push((x.clone(), I::zero()));
+
+        for _ in 0..steps {
+            let y = (self.relation)(x.clone());
+
+            points.push((x, y.clone()));
+            points.push((y.clone(), y.clone()));
+
+            x = y;
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cobweb() {
+        let relation = Relation::new(1, |x| x * 2);
+
+        assert_eq!(relation.cobweb(2), [(1, 0), (1, 2), (2, 2), (2, 4), (4, 4)]);
+
+        assert_eq!(relation.cobweb(0), [(1, 0)]);
+    }
+}
@@ -0,0 +1,73 @@
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+use crate::{Magnitude, Relation};
+
+impl<T: Float> Magnitude for Complex<T> {
+    type Output = T;
+
+    /// Returns the modulus of the complex number
+    fn magnitude(&self) -> Self::Output {
+        self.norm()
+    }
+}
+
+impl<T: Num + Copy> Relation<Complex<T>, ()> {
+    /// Create a new [`Relation`] for the quadratic map `z -> z^2 + c`
+    ///
+    /// This is the map used to draw the Mandelbrot and Julia sets
+    pub fn quadratic_map(
+        starter: Complex<T>,
+        c: Complex<T>,
+    ) -> Relation<Complex<T>, impl FnOnce(Complex<T>) -> Complex<T> + Copy> {
+        Relation::new(starter, move |z: Complex<T>| z * z + c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_relation() {
+        let mut relation = Relation::new(Complex::new(1., 0.), |z| z * Complex::i());
+
+        assert_eq!(relation.calculate_next(), Complex::new(0., 1.));
+
+        assert_eq!(relation.nth(4), Complex::new(1., 0.));
+    }
+
+    #[test]
+    fn test_bounded_quadratic_map() {
+        // -1 lies in the Mandelbrot set, cycling between 0 and -1
+        let relation = Relation::quadratic_map(Complex::new(0., 0.), Complex::new(-1., 0.));
+
+        assert!(relation.take(5).eq([
+            Complex::new(0., 0.),
+            Complex::new(-1., 0.),
+            Complex::new(0., 0.),
+            Complex::new(-1., 0.),
+            Complex::new(0., 0.),
+        ]));
+    }
+
+    #[test]
+    fn test_escaping_quadratic_map() {
+        let mut relation = Relation::quadratic_map(Complex::new(0., 0.), Complex::new(1., 1.));
+
+        assert_eq!(relation.nth(2), Complex::new(1., 3.));
+
+        assert!(relation.nth(3).norm() > 2.);
+    }
+
+    #[test]
+    fn test_mandelbrot_escape_time() {
+        let escape_time = |c| Relation::quadratic_map(Complex::new(0., 0.), c).escape_time(2., 100);
+
+        assert_eq!(escape_time(Complex::new(-1., 0.)), None);
+
+        assert_eq!(escape_time(Complex::new(1., 1.)), Some(2));
+
+        assert_eq!(escape_time(Complex::new(3., 0.)), Some(1));
+    }
+}
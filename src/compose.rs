@@ -0,0 +1,57 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Returns a relation which applies the relation function and then `rule` each step, keeping the current number and index
+    ///
+    /// This builds a pipeline from small rules, such as growth, then a fee, then tax
+    pub fn then<G: FnOnce(I) -> I + Copy>(
+        self,
+        rule: G,
+    ) -> Relation<I, impl FnOnce(I) -> I + Copy> {
+        let relation = self.relation;
+
+        self.with_relation(move |x| rule(relation(x)))
+    }
+
+    /// Returns a relation which applies `rule` and then the relation function each step, keeping the current number and index
+    ///
+    /// This is the mathematical composition `f ∘ rule`, and the reverse of [`Relation::then`]
+    pub fn compose<G: FnOnce(I) -> I + Copy>(
+        self,
+        rule: G,
+    ) -> Relation<I, impl FnOnce(I) -> I + Copy> {
+        let relation = self.relation;
+
+        self.with_relation(move |x| relation(rule(x)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_then() {
+        let growth = Relation::new(1000, |x| x * 2);
+
+        let after_fees = growth.then(|x| x - 100).then(|x| x / 2);
+
+        assert!(after_fees.take(3).eq([1000, 950, 900]));
+    }
+
+    #[test]
+    fn test_compose() {
+        let mut relation = Relation::new(1, |x| x * 3);
+        relation.next();
+
+        let mut composed = relation.compose(|x| x + 1);
+
+        assert_eq!(composed.index(), 1);
+
+        assert_eq!(composed.nth(1), 12);
+
+        assert_eq!(relation.then(|x| x + 1).nth(1), 10);
+    }
+}
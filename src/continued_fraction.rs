@@ -0,0 +1,121 @@
+//! Convergents of continued fractions
+//!
+//! A continued fraction `[a0; a1, a2, ...]` is `a0 + 1 / (a1 + 1 / (a2 + ...))`. Its convergents `p(n) / q(n)`
+//! are the best rational approximations to it, and follow the three term recurrences
+//! `p(n) = a(n) p(n-1) + p(n-2)` and `q(n) = a(n) q(n-1) + q(n-2)`
+
+use num_traits::Num;
+
+/// An iterator over the convergents of a continued fraction, as `(numerator, denominator)` pairs
+///
+/// Created by [`convergents`]. The numerators and denominators grow exponentially,
+/// so long expansions may need a big integer type to avoid overflow
+#[derive(Debug, Clone)]
+pub struct Convergents<I, A> {
+    coefficients: A,
+    // The convergents before the current one, as (p(n-1), q(n-1)) and (p(n-2), q(n-2))
+    previous: (I, I),
+    before_previous: (I, I),
+}
+
+/// Returns the convergents of the continued fraction with `coefficients`, beginning with the whole part `a0`
+///
+/// The iterator ends with the coefficients, so a finite continued fraction ends with its exact value
+pub fn convergents<I: Num + Clone, A: IntoIterator<Item = I>>(
+    coefficients: A,
+) -> Convergents<I, A::IntoIter> {
+    Convergents {
+        coefficients: coefficients.into_iter(),
+        previous: (I::one(), I::zero()),
+        before_previous: (I::zero(), I::one()),
+    }
+}
+
+/// Returns the convergents of the golden ratio `[1; 1, 1, ...]`, which are ratios of consecutive Fibonacci numbers
+pub fn golden_ratio<I: Num + Clone>() -> Convergents<I, core::iter::Repeat<I>> {
+    convergents(core::iter::repeat(I::one()))
+}
+
+/// Returns the convergents of `√2`, which is `[1; 2, 2, ...]`
+pub fn sqrt_two<I: Num + Clone>(
+) -> Convergents<I, core::iter::Chain<core::iter::Once<I>, core::iter::Repeat<I>>> {
+    convergents(core::iter::once(I::one()).chain(core::iter::repeat(I::one() + I::one())))
+}
+
+impl<I: Num + Clone, A: Iterator<Item = I>> Iterator for Convergents<I, A> {
+    type Item = (I, I);
+
+    /// Yields the next convergent as `(p(n), q(n))`
+    fn next(&mut self) -> Option<Self::Item> {
+        let coefficient = self.coefficients.next()?;
+
+        let next = (
+            coefficient.clone() * self.previous.0.clone() + self.before_previous.0.clone(),
+            coefficient * self.previous.1.clone() + self.before_previous.1.clone(),
+        );
+
+        self.before_previous = core::mem::replace(&mut self.previous, next.clone());
+
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.coefficients.size_hint()
+    }
+}
+
+#[cfg(feature = "rational")]
+impl<I: Num + Clone + num_integer::Integer, A: Iterator<Item = I>> Convergents<I, A> {
+    /// Converts the convergents into exact fractions
+    pub fn ratios(self) -> impl Iterator<Item = num_rational::Ratio<I>> {
+        self.map(|(numerator, denominator)| num_rational::Ratio::new(numerator, denominator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_golden_ratio() {
+        assert!(golden_ratio::<u64>().take(6).eq([
+            (1, 1),
+            (2, 1),
+            (3, 2),
+            (5, 3),
+            (8, 5),
+            (13, 8)
+        ]));
+
+        let (p, q) = golden_ratio::<u64>().nth(40).unwrap();
+
+        assert!((p as f64 / q as f64 - (1. + 5f64.sqrt()) / 2.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_sqrt_two() {
+        assert!(sqrt_two::<i64>()
+            .take(5)
+            .eq([(1, 1), (3, 2), (7, 5), (17, 12), (41, 29)]));
+    }
+
+    #[test]
+    fn test_finite_continued_fraction() {
+        // 415 / 93 = [4; 2, 6, 7]
+        assert_eq!(convergents([4, 2, 6, 7]).last(), Some((415, 93)));
+
+        assert_eq!(convergents([4, 2, 6, 7]).count(), 4);
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn test_ratios() {
+        use num_rational::Ratio;
+
+        assert!(sqrt_two::<i64>().ratios().take(3).eq([
+            Ratio::from(1),
+            Ratio::new(3, 2),
+            Ratio::new(7, 5)
+        ]));
+    }
+}
@@ -0,0 +1,325 @@
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+use num_traits::Num;
+
+use crate::{abs_diff, trace, Convergence, Relation};
+
+/// Why a controlled run stopped before it finished
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Interruption {
+    /// The run used up its step budget
+    StepBudget,
+    /// The run went on for longer than its timeout
+    Timeout,
+    /// The run was cancelled with its [`CancelToken`]
+    Cancelled,
+}
+
+/// A controlled run which stopped before it finished, along with the state it had reached
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Interrupted<S> {
+    /// Why the run stopped
+    pub reason: Interruption,
+    /// The number of steps taken before the run stopped
+    pub steps: usize,
+    /// The partial result of the run
+    pub partial: S,
+}
+
+/// A flag shared between threads, which cooperatively cancels the runs it is given to
+///
+/// Cloning the token shares the same flag, so cancelling any clone cancels them all
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+#[cfg(feature = "alloc")]
+impl CancelToken {
+    /// Create a new [`CancelToken`], which is not yet cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels every run given this token, which stop the next time they check it
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The limits on how long a controlled run may go on for, which by default are unlimited
+///
+/// The step budget is checked on every step, while the timeout and cancellation token
+/// are checked every [`RunControl::with_check_interval`] steps, as they are slower to check
+#[derive(Debug, Clone)]
+pub struct RunControl {
+    max_steps: Option<usize>,
+    #[cfg(feature = "std")]
+    timeout: Option<Duration>,
+    #[cfg(feature = "alloc")]
+    cancel: Option<CancelToken>,
+    check_interval: usize,
+}
+
+impl Default for RunControl {
+    fn default() -> Self {
+        Self {
+            max_steps: None,
+            #[cfg(feature = "std")]
+            timeout: None,
+            #[cfg(feature = "alloc")]
+            cancel: None,
+            check_interval: 1000,
+        }
+    }
+}
+
+impl RunControl {
+    /// Create a new [`RunControl`] without any limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the run after `max_steps` steps
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            ..self
+        }
+    }
+
+    /// Stops the run once it has gone on for longer than `timeout`
+    #[cfg(feature = "std")]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Stops the run once `token` is cancelled
+    #[cfg(feature = "alloc")]
+    pub fn with_cancel_token(self, token: CancelToken) -> Self {
+        Self {
+            cancel: Some(token),
+            ..self
+        }
+    }
+
+    /// Checks the timeout and cancellation token every `interval` steps, which is every 1000 steps by default
+    pub fn with_check_interval(self, interval: usize) -> Self {
+        Self {
+            check_interval: interval.max(1),
+            ..self
+        }
+    }
+
+    /// Starts the clock for a run
+    pub(crate) fn start(&self) -> RunGuard<'_> {
+        RunGuard {
+            control: self,
+            #[cfg(feature = "std")]
+            started: Instant::now(),
+        }
+    }
+}
+
+/// The limits of a [`RunControl`] for a run which has started
+pub(crate) struct RunGuard<'a> {
+    control: &'a RunControl,
+    #[cfg(feature = "std")]
+    started: Instant,
+}
+
+impl RunGuard<'_> {
+    /// Checks whether a run which has taken `steps` steps should stop before taking another
+    pub(crate) fn check(&self, steps: usize) -> Result<(), Interruption> {
+        if self
+            .control
+            .max_steps
+            .is_some_and(|max_steps| steps >= max_steps)
+        {
+            return Err(Interruption::StepBudget);
+        }
+
+        if !steps.is_multiple_of(self.control.check_interval) {
+            return Ok(());
+        }
+
+        #[cfg(feature = "alloc")]
+        if self
+            .control
+            .cancel
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+        {
+            return Err(Interruption::Cancelled);
+        }
+
+        #[cfg(feature = "std")]
+        if self
+            .control
+            .timeout
+            .is_some_and(|timeout| self.started.elapsed() > timeout)
+        {
+            return Err(Interruption::Timeout);
+        }
+
+        Ok(())
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calls [`Relation::next`] until successive terms differ by less than `tolerance`, like [`Relation::converge`],
+    /// but within the limits of `control`
+    ///
+    /// # Errors
+    /// - If the run is stopped by `control`, with the current number as the partial result
+    pub fn converge_controlled(
+        &mut self,
+        tolerance: I,
+        control: &RunControl,
+    ) -> Result<Convergence<I>, Interrupted<I>> {
+        trace::span!("converge_controlled", start_index = self.index);
+
+        let guard = control.start();
+        let mut steps = 0;
+
+        loop {
+            if let Err(reason) = guard.check(steps) {
+                trace::event!(WARN, steps, ?reason, "convergence interrupted");
+
+                return Err(Interrupted {
+                    reason,
+                    steps,
+                    partial: self.current_number.clone(),
+                });
+            }
+
+            let previous = self.current_number.clone();
+            self.next();
+            steps += 1;
+
+            if abs_diff(&self.current_number, &previous) < tolerance {
+                trace::event!(DEBUG, steps, "converged");
+
+                return Ok(Convergence {
+                    limit: self.current_number.clone(),
+                    steps,
+                });
+            }
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Finds the first term, from the current number onwards, for which `predicate` holds, like [`Relation::find`],
+    /// but within the limits of `control`, without updating the struct
+    ///
+    /// # Errors
+    /// - If the run is stopped by `control`, with the index and value of the last term checked as the partial result
+    pub fn find_controlled(
+        &self,
+        mut predicate: impl FnMut(&I) -> bool,
+        control: &RunControl,
+    ) -> Result<(usize, I), Interrupted<(usize, I)>> {
+        let guard = control.start();
+        let mut number = self.current_number.clone();
+        let mut steps = 0;
+
+        while !predicate(&number) {
+            if let Err(reason) = guard.check(steps) {
+                return Err(Interrupted {
+                    reason,
+                    steps,
+                    partial: (self.index + steps, number),
+                });
+            }
+
+            number = (self.relation)(number);
+            steps += 1;
+        }
+
+        Ok((self.index + steps, number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_budget() {
+        let mut relation = Relation::new(1., |x: f64| x / 2.);
+
+        let convergence = relation
+            .converge_controlled(1e-6, &RunControl::new())
+            .unwrap();
+
+        assert_eq!(convergence.steps, 20);
+
+        let mut relation = Relation::new(1., |x: f64| x / 2.);
+
+        assert_eq!(
+            relation.converge_controlled(1e-6, &RunControl::new().with_max_steps(3)),
+            Err(Interrupted {
+                reason: Interruption::StepBudget,
+                steps: 3,
+                partial: 0.125,
+            })
+        );
+
+        assert_eq!(relation.index(), 3);
+
+        let counter = Relation::new(0, |x| x + 1);
+
+        assert_eq!(
+            counter.find_controlled(|&x| x == 50, &RunControl::new().with_max_steps(100)),
+            Ok((50, 50))
+        );
+
+        assert_eq!(
+            counter.find_controlled(|&x| x == 500, &RunControl::new().with_max_steps(100)),
+            Err(Interrupted {
+                reason: Interruption::StepBudget,
+                steps: 100,
+                partial: (100, 100),
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_timeout_and_cancellation() {
+        let counter = Relation::new(0u64, |x| x + 1);
+
+        let control = RunControl::new().with_timeout(Duration::ZERO);
+
+        let interrupted = counter.find_controlled(|_| false, &control).unwrap_err();
+
+        assert_eq!(interrupted.reason, Interruption::Timeout);
+
+        let token = CancelToken::new();
+        let control = RunControl::new()
+            .with_cancel_token(token.clone())
+            .with_check_interval(10);
+
+        let canceller = std::thread::spawn(move || token.cancel());
+        canceller.join().unwrap();
+
+        let interrupted = counter.find_controlled(|_| false, &control).unwrap_err();
+
+        assert_eq!(interrupted.reason, Interruption::Cancelled);
+
+        assert_eq!(interrupted.steps, 0);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::{self, Write},
+    string::ToString,
+};
+
+#[cfg(feature = "parse")]
+use num_traits::Float;
+use num_traits::Num;
+
+#[cfg(feature = "parse")]
+use crate::ExpressionRelation;
+use crate::{
+    finance::{AmortizationRow, Loan},
+    Named, Relation, RelationLike,
+};
+
+/// The columns of an amortization schedule written by [`AmortizationRow::export_csv`]
+const SCHEDULE_COLUMNS: [&str; 6] = [
+    "period",
+    "opening_balance",
+    "interest",
+    "payment",
+    "principal",
+    "closing_balance",
+];
+
+/// Quotes a field if it contains a comma, a quote or a line break, doubling any quotes within it
+fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(std::format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Writes one line of comma separated fields
+fn write_record<W: Write, D: Display>(
+    writer: &mut W,
+    fields: impl IntoIterator<Item = D>,
+) -> io::Result<()> {
+    for (position, field) in fields.into_iter().enumerate() {
+        if position > 0 {
+            writer.write_all(b",")?;
+        }
+
+        writer.write_all(escape(&field.to_string()).as_bytes())?;
+    }
+
+    writer.write_all(b"\n")
+}
+
+/// Writes a header of `index` and `heading`, followed by one line per term
+pub(crate) fn write_terms<I: Display>(
+    mut writer: impl Write,
+    heading: &str,
+    first_index: usize,
+    terms: impl Iterator<Item = I>,
+) -> io::Result<()> {
+    write_record(&mut writer, ["index", heading])?;
+
+    for (index, term) in (first_index..).zip(terms) {
+        write!(writer, "{index},")?;
+        write_record(&mut writer, [term])?;
+    }
+
+    writer.flush()
+}
+
+impl<I: Num + Clone + Display, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Writes the index and value of the next `count` terms as comma separated values, beginning with the current number,
+    /// without updating the struct
+    ///
+    /// The first line is the header `index,value`, so the output can be opened directly as a spreadsheet
+    ///
+    /// # Errors
+    /// - If writing to `writer` fails
+    pub fn export_csv(&self, writer: impl Write, count: usize) -> io::Result<()> {
+        RelationLike::export_csv(self, writer, count)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl<I: Float + Display> ExpressionRelation<I> {
+    /// Writes the index and value of the next `count` terms as comma separated values, like [`Relation::export_csv`]
+    ///
+    /// # Errors
+    /// - If writing to `writer` fails
+    pub fn export_csv(&self, writer: impl Write, count: usize) -> io::Result<()> {
+        RelationLike::export_csv(self, writer, count)
+    }
+}
+
+impl<I: Num + Clone + Display, F: FnOnce(I) -> I + Copy> Named<Relation<I, F>> {
+    /// Runs [`Relation::export_csv`], with the label and unit of the metadata heading the column of values
+    ///
+    /// # Errors
+    /// - If writing to `writer` fails
+    pub fn export_csv(&self, writer: impl Write, count: usize) -> io::Result<()> {
+        let heading = self.metadata().heading("value");
+
+        write_terms(
+            writer,
+            &heading,
+            self.index,
+            self.inner().clone().take(count),
+        )
+    }
+}
+
+impl<I: Display> AmortizationRow<I> {
+    /// Writes `rows` as comma separated values, with a header naming each column of the schedule
+    ///
+    /// # Errors
+    /// - If writing to `writer` fails
+    pub fn export_csv(
+        mut writer: impl Write,
+        rows: impl IntoIterator<Item = Self>,
+    ) -> io::Result<()> {
+        write_record(&mut writer, SCHEDULE_COLUMNS)?;
+
+        for row in rows {
+            write!(writer, "{},", row.period)?;
+            write_record(
+                &mut writer,
+                [
+                    row.opening_balance,
+                    row.interest,
+                    row.payment,
+                    row.principal,
+                    row.closing_balance,
+                ],
+            )?;
+        }
+
+        writer.flush()
+    }
+}
+
+impl<I: Num + Clone + PartialOrd + Display> Loan<I> {
+    /// Writes the full amortization schedule as comma separated values, with one line per period
+    ///
+    /// See [`AmortizationRow::export_csv`] for the columns written
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest, as an error of kind [`io::ErrorKind::InvalidInput`]
+    /// - If writing to `writer` fails
+    pub fn export_csv(&self, writer: impl Write) -> io::Result<()> {
+        let rows = self
+            .rows()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        AmortizationRow::export_csv(writer, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn test_export_csv() {
+        let mut output = Vec::new();
+
+        Relation::new(1., |x| x * 1.5)
+            .export_csv(&mut output, 3)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "index,value\n0,1\n1,1.5\n2,2.25\n"
+        );
+
+        let mut output = Vec::new();
+        let mut relation = Relation::new(0, |x| x + 1).named("Count, total");
+        relation.nth(5);
+
+        relation.export_csv(&mut output, 2).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "index,\"Count, total\"\n5,5\n6,6\n"
+        );
+    }
+
+    #[test]
+    fn test_export_schedule_csv() {
+        let mut output = Vec::new();
+
+        Loan::new(1000, 1, 1500).export_csv(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "period,opening_balance,interest,payment,principal,closing_balance\n\
+             1,1000,1000,1500,500,500\n\
+             2,500,500,1000,500,0\n"
+        );
+
+        let error = Loan::new(10_000., 0.05, 500.)
+            .export_csv(Vec::new())
+            .unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
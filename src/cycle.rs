@@ -0,0 +1,98 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// The shape of a sequence which eventually repeats
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of terms before the cycle begins
+    pub tail: usize,
+    /// The number of terms in the cycle
+    pub length: usize,
+}
+
+impl<I: Num + Clone + PartialEq, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Detects whether the sequence, from the current number onwards, eventually cycles, without updating the struct
+    ///
+    /// Uses Brent's algorithm, so only a couple of terms are stored at a time.
+    /// Returns [`None`] if no cycle is found within `max_steps` steps
+    pub fn detect_cycle(&self, max_steps: usize) -> Option<Cycle> {
+        let relation = self.relation;
+
+        // Find the cycle length, by moving the tortoise to the hare at every power of two
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = self.current_number.clone();
+        let mut hare = relation(tortoise.clone());
+        let mut steps = 1;
+
+        while tortoise != hare {
+            if steps >= max_steps {
+                return None;
+            }
+
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+
+            hare = relation(hare);
+            length += 1;
+            steps += 1;
+        }
+
+        // Find the start of the cycle, with the hare `length` terms ahead of the tortoise
+        let mut tortoise = self.current_number.clone();
+        let mut hare = tortoise.clone();
+        for _ in 0..length {
+            hare = relation(hare);
+        }
+
+        let mut tail = 0;
+        while tortoise != hare {
+            tortoise = relation(tortoise);
+            hare = relation(hare);
+            tail += 1;
+        }
+
+        Some(Cycle { tail, length })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_cycle() {
+        // 2 -> 4 -> 16 -> 31 -> 16 -> 31, modulo 45
+        let relation = Relation::new(2u32, |x| x * x % 45);
+
+        assert_eq!(
+            relation.detect_cycle(100),
+            Some(Cycle { tail: 2, length: 2 })
+        );
+    }
+
+    #[test]
+    fn test_pure_cycle() {
+        let relation = Relation::new(0u8, |x| (x + 3) % 7);
+
+        assert_eq!(
+            relation.detect_cycle(100),
+            Some(Cycle { tail: 0, length: 7 })
+        );
+
+        let fixed = Relation::new(5, |x| x);
+
+        assert_eq!(fixed.detect_cycle(1), Some(Cycle { tail: 0, length: 1 }));
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        let relation = Relation::new(0u64, |x| x + 1);
+
+        assert_eq!(relation.detect_cycle(1000), None);
+    }
+}
@@ -0,0 +1,87 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::Relation;
+
+/// How each term of a decimal relation is rounded
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rounding {
+    /// The number of decimal places to keep
+    pub decimal_places: u32,
+    /// The strategy used to round to those decimal places
+    pub strategy: RoundingStrategy,
+}
+
+impl Rounding {
+    /// Create a new [`Rounding`]
+    pub const fn new(decimal_places: u32, strategy: RoundingStrategy) -> Self {
+        Self {
+            decimal_places,
+            strategy,
+        }
+    }
+
+    /// Rounds to whole cents, using banker's rounding
+    pub const fn cents() -> Self {
+        Self::new(2, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds the given number
+    pub fn apply(&self, number: Decimal) -> Decimal {
+        number.round_dp_with_strategy(self.decimal_places, self.strategy)
+    }
+}
+
+impl Relation<Decimal, ()> {
+    /// Create a new [`Relation`] which rounds the starter, and every term after each step
+    pub fn rounded(
+        starter: Decimal,
+        rounding: Rounding,
+        relation: impl FnOnce(Decimal) -> Decimal + Copy,
+    ) -> Relation<Decimal, impl FnOnce(Decimal) -> Decimal + Copy> {
+        Relation::new(rounding.apply(starter), move |x| {
+            rounding.apply(relation(x))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_decimal_relation() {
+        let mut relation = Relation::new(dec!(0.1), |x| x + dec!(0.1));
+
+        assert_eq!(relation.nth(2), dec!(0.3));
+    }
+
+    #[test]
+    fn test_rounded_relation() {
+        let mut relation = Relation::rounded(dec!(1000), Rounding::cents(), |x| x * dec!(1.00375));
+
+        assert_eq!(relation.calculate_next(), dec!(1003.75));
+
+        relation.next();
+
+        // 1007.5140625 before rounding
+        assert_eq!(relation.calculate_next(), dec!(1007.51));
+
+        assert_eq!(relation.nth(2), dec!(1011.29));
+    }
+
+    #[test]
+    fn test_rounding_strategy() {
+        let round_up = Rounding::new(0, RoundingStrategy::AwayFromZero);
+
+        let mut relation = Relation::rounded(dec!(1), round_up, |x| x * dec!(1.1));
+
+        assert!(relation
+            .by_ref()
+            .take(4)
+            .eq([dec!(1), dec!(2), dec!(3), dec!(4)]));
+
+        assert_eq!(relation.nth(0), dec!(5));
+    }
+}
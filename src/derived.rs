@@ -0,0 +1,356 @@
+use num_traits::{Float, Num};
+
+use crate::Relation;
+
+/// The series of a relation, whose terms are the running totals of the relation's terms, where `S(n) = S(n-1) + t(n)`
+///
+/// Created by [`Relation::partial_sums`]. The first partial sum is the current number of the relation
+#[derive(Debug, Copy, Clone)]
+pub struct PartialSums<I, F> {
+    relation: Relation<I, F>,
+    sum: I,
+}
+
+/// The series of a floating point relation, summed with Neumaier's compensated summation
+///
+/// Created by [`Relation::compensated_partial_sums`]. The rounding error of each addition is tracked
+/// and added back, so long series do not drift as they would with [`PartialSums`]
+#[derive(Debug, Copy, Clone)]
+pub struct CompensatedSums<I, F> {
+    relation: Relation<I, F>,
+    sum: I,
+    compensation: I,
+}
+
+/// The forward differences of a relation, where `d(n) = t(n+1) - t(n)`
+///
+/// Created by [`Relation::differences`]. A constant difference sequence identifies an arithmetic relation,
+/// and a difference sequence proportional to the relation identifies a geometric one
+#[derive(Debug, Copy, Clone)]
+pub struct Differences<I, F> {
+    relation: Relation<I, F>,
+}
+
+/// The ratios between successive terms of a relation, where `r(n) = t(n+1) / t(n)`
+///
+/// Created by [`Relation::ratios`]. A constant ratio identifies a geometric relation.
+/// The ratio is [`None`] when the earlier term is zero, rather than dividing by zero
+#[derive(Debug, Copy, Clone)]
+pub struct Ratios<I, F> {
+    relation: Relation<I, F>,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Turns the relation into its series, beginning with the current number
+    pub fn partial_sums(self) -> PartialSums<I, F> {
+        PartialSums {
+            sum: self.current_number.clone(),
+            relation: self,
+        }
+    }
+
+    /// Turns the relation into its forward differences, beginning with the difference after the current number
+    pub fn differences(self) -> Differences<I, F> {
+        Differences { relation: self }
+    }
+
+    /// Turns the relation into the ratios between its successive terms, beginning with the ratio after the current number
+    pub fn ratios(self) -> Ratios<I, F> {
+        Ratios { relation: self }
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Turns the relation into its series like [`Relation::partial_sums`], using compensated summation to avoid rounding drift
+    pub fn compensated_partial_sums(self) -> CompensatedSums<I, F> {
+        CompensatedSums {
+            sum: self.current_number,
+            compensation: I::zero(),
+            relation: self,
+        }
+    }
+
+    /// Turns the relation into the log returns between its successive terms, where `l(n) = ln(t(n+1) / t(n))`
+    ///
+    /// The log return is [`None`] when the ratio is not positive, as it has no real logarithm
+    pub fn log_returns(self) -> impl Iterator<Item = Option<I>> {
+        self.ratios()
+            .map(|ratio| ratio.filter(|&ratio| ratio > I::zero()).map(I::ln))
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> PartialSums<I, F> {
+    /// Returns the current partial sum
+    pub fn current(&self) -> &I {
+        &self.sum
+    }
+
+    /// Returns the most recent term added to the sum
+    pub fn term(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the most recent term added to the sum
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Calculates and returns the next partial sum, without updating the struct
+    pub fn calculate_next(&self) -> I {
+        self.sum.clone() + self.relation.calculate_next()
+    }
+
+    /// Adds the next term to the sum and updates the struct
+    pub fn next(&mut self) {
+        self.relation.next();
+        self.sum = self.sum.clone() + self.relation.current_number.clone();
+    }
+
+    /// Calls [`PartialSums::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.sum.clone()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for PartialSums<I, F> {
+    type Item = I;
+
+    /// Yields the current partial sum and then adds the next term
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.sum.clone();
+        PartialSums::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> CompensatedSums<I, F> {
+    /// Returns the current partial sum, including the compensation for rounding error
+    pub fn current(&self) -> I {
+        self.sum + self.compensation
+    }
+
+    /// Returns the most recent term added to the sum
+    pub fn term(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the most recent term added to the sum
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Adds the next term to the sum and updates the struct
+    pub fn next(&mut self) {
+        self.relation.next();
+
+        let term = self.relation.current_number;
+        let sum = self.sum + term;
+
+        // Recover the low order digits lost by whichever of the two was smaller
+        self.compensation = self.compensation
+            + if self.sum.abs() >= term.abs() {
+                (self.sum - sum) + term
+            } else {
+                (term - sum) + self.sum
+            };
+
+        self.sum = sum;
+    }
+
+    /// Calls [`CompensatedSums::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current()
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Iterator for CompensatedSums<I, F> {
+    type Item = I;
+
+    /// Yields the current partial sum and then adds the next term
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        CompensatedSums::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Differences<I, F> {
+    /// Returns the difference between the next number of the relation and its current number
+    pub fn current(&self) -> I {
+        self.relation.calculate_next() - self.relation.current_number.clone()
+    }
+
+    /// Returns the index of the current difference, which is the index of the earlier of its two terms
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Advances the relation by one step
+    pub fn next(&mut self) {
+        self.relation.next();
+    }
+
+    /// Calls [`Differences::next`] `index` times, and then returns the current difference
+    pub fn nth(&mut self, index: usize) -> I {
+        self.relation.nth(index);
+
+        self.current()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for Differences<I, F> {
+    type Item = I;
+
+    /// Yields the current difference and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        Differences::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Ratios<I, F> {
+    /// Returns the ratio of the next number of the relation to its current number,
+    /// or [`None`] if the current number is zero
+    pub fn current(&self) -> Option<I> {
+        let current = self.relation.current_number.clone();
+
+        (!current.is_zero()).then(|| self.relation.calculate_next() / current)
+    }
+
+    /// Returns the index of the current ratio, which is the index of the earlier of its two terms
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Advances the relation by one step
+    pub fn next(&mut self) {
+        self.relation.next();
+    }
+
+    /// Calls [`Ratios::next`] `index` times, and then returns the current ratio
+    pub fn nth(&mut self, index: usize) -> Option<I> {
+        self.relation.nth(index);
+
+        self.current()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for Ratios<I, F> {
+    type Item = Option<I>;
+
+    /// Yields the current ratio and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        Ratios::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_sums() {
+        let mut series = Relation::new(1, |x| x * 2).partial_sums();
+
+        assert_eq!(series.nth(9), 1023);
+
+        assert_eq!(series.term(), &512);
+
+        assert_eq!(series.calculate_next(), 2047);
+
+        let arithmetic = Relation::new(1, |x| x + 1).partial_sums();
+
+        assert!(arithmetic.take(5).eq([1, 3, 6, 10, 15]));
+    }
+
+    #[test]
+    fn test_compensated_partial_sums() {
+        // Each tiny term is lost to rounding when added to 1 directly
+        let relation = Relation::new(1., |_| 1e-16);
+
+        assert_eq!(relation.partial_sums().nth(1000), 1.);
+
+        assert!((relation.compensated_partial_sums().nth(1000) - (1. + 1e-13)).abs() < 1e-15);
+
+        let tenths = Relation::new(0.1, |x: f64| x);
+
+        let plain_error = (tenths.partial_sums().nth(99_999) - 10_000.).abs();
+        let compensated_error = (tenths.compensated_partial_sums().nth(99_999) - 10_000.).abs();
+
+        assert!(compensated_error < plain_error);
+    }
+
+    #[test]
+    fn test_differences() {
+        let mut differences = Relation::new(0, |x| x + 2).differences();
+
+        assert!(differences.take(3).eq([2, 2, 2]));
+
+        assert_eq!(differences.nth(5), 2);
+
+        assert_eq!(differences.index(), 5);
+
+        // The differences of a geometric relation are proportional to its terms
+        let geometric = Relation::new(3, |x| x * 3).differences();
+
+        assert!(geometric.take(4).eq([6, 18, 54, 162]));
+    }
+
+    #[test]
+    fn test_ratios() {
+        let mut ratios = Relation::new(5., |x| x * 1.5).ratios();
+
+        assert_eq!(ratios.current(), Some(1.5));
+
+        assert_eq!(ratios.nth(10), Some(1.5));
+
+        let through_zero = Relation::new(2, |x| x - 2).ratios();
+
+        assert!(through_zero.take(3).eq([Some(0), None, Some(2)]));
+
+        let log_returns = Relation::new(100., |x| x * 2.).log_returns();
+
+        assert!(log_returns.take(2).eq([Some(2f64.ln()); 2]));
+
+        let sign_change = Relation::new(1., |x: f64| -x).log_returns();
+
+        assert!(sign_change.take(2).eq([None, None]));
+    }
+}
@@ -0,0 +1,102 @@
+use num_traits::Float;
+
+use crate::{trace, Error, Relation};
+
+/// Why a floating point relation was considered to have diverged
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The term was `NaN`
+    NaN,
+    /// The term was positive or negative infinity
+    Infinite,
+    /// The magnitude of the term exceeded the threshold
+    ExceededThreshold,
+}
+
+impl Divergence {
+    /// Checks whether `number` has diverged, optionally with a threshold for its magnitude
+    pub fn check<I: Float>(number: I, max_magnitude: Option<I>) -> Option<Self> {
+        if number.is_nan() {
+            Some(Divergence::NaN)
+        } else if number.is_infinite() {
+            Some(Divergence::Infinite)
+        } else if max_magnitude.is_some_and(|max_magnitude| number.abs() > max_magnitude) {
+            Some(Divergence::ExceededThreshold)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calls [`Relation::next`] `index` times, and then returns the result, stopping early if the relation diverges
+    ///
+    /// A term diverges if it is `NaN`, infinite, or its magnitude exceeds `max_magnitude`
+    ///
+    /// # Errors
+    /// - If a term diverges, in which case the struct is left at the last term before it
+    pub fn guarded_nth(&mut self, index: usize, max_magnitude: Option<I>) -> Result<I, Error> {
+        for _ in 0..index {
+            let next = self.calculate_next();
+
+            if let Some(reason) = Divergence::check(next, max_magnitude) {
+                trace::event!(WARN, index = self.index + 1, ?reason, "relation diverged");
+
+                return Err(Error::Diverged {
+                    index: self.index + 1,
+                    reason,
+                });
+            }
+
+            self.current_number = next;
+            self.index += 1;
+        }
+
+        Ok(self.current_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded_nth() {
+        let mut relation = Relation::new(1., |x: f64| x * 10.);
+
+        assert_eq!(relation.guarded_nth(3, Some(1e6)), Ok(1000.));
+
+        assert_eq!(
+            relation.guarded_nth(10, Some(1e6)),
+            Err(Error::Diverged {
+                index: 7,
+                reason: Divergence::ExceededThreshold
+            })
+        );
+
+        assert_eq!(relation.current(), &1e6);
+
+        assert_eq!(
+            relation.guarded_nth(1000, None),
+            Err(Error::Diverged {
+                index: 309,
+                reason: Divergence::Infinite
+            })
+        );
+    }
+
+    #[test]
+    fn test_nan_detection() {
+        let mut relation = Relation::new(4., |x: f64| x.ln() - 1.);
+
+        assert_eq!(
+            relation.guarded_nth(10, None),
+            Err(Error::Diverged {
+                index: 3,
+                reason: Divergence::NaN
+            })
+        );
+
+        assert_eq!(relation.index(), 2);
+    }
+}
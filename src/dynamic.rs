@@ -0,0 +1,138 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::RelationLike;
+
+/// A [`RelationLike`] which can also clone itself into a box, so a [`DynRelation`] can be cloned
+trait CloneRelation<I>: RelationLike<I> {
+    fn clone_box(&self) -> Box<dyn CloneRelation<I>>;
+}
+
+impl<I, R: RelationLike<I> + Clone + 'static> CloneRelation<I> for R {
+    fn clone_box(&self) -> Box<dyn CloneRelation<I>> {
+        Box::new(self.clone())
+    }
+}
+
+/// A boxed relation of any type, with the rule erased
+///
+/// Every [`DynRelation`] with the same number type has the same type, whatever closure or kind of relation it holds,
+/// so relations with different rules can be stored in one collection, or replaced by another at runtime
+pub struct DynRelation<I> {
+    relation: Box<dyn CloneRelation<I>>,
+}
+
+impl<I: Clone> DynRelation<I> {
+    /// Create a new [`DynRelation`] by boxing `relation`
+    pub fn new(relation: impl RelationLike<I> + Clone + 'static) -> Self {
+        Self {
+            relation: Box::new(relation),
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.relation.index()
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        self.relation.calculate_next()
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.relation.advance();
+    }
+
+    /// Calls [`DynRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current().clone()
+    }
+
+    /// Replaces the boxed relation with `relation`, returning the one it held
+    pub fn replace(&mut self, relation: impl RelationLike<I> + Clone + 'static) -> Self {
+        core::mem::replace(self, Self::new(relation))
+    }
+}
+
+impl<I> Clone for DynRelation<I> {
+    fn clone(&self) -> Self {
+        Self {
+            relation: self.relation.clone_box(),
+        }
+    }
+}
+
+impl<I: fmt::Debug> fmt::Debug for DynRelation<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynRelation")
+            .field("current", self.relation.current())
+            .field("index", &self.relation.index())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Clone> Iterator for DynRelation<I> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current().clone();
+        DynRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+    use crate::{AffineRelation, IndexedRelation, Recurrence, Relation, RelationLike};
+
+    #[test]
+    fn test_dyn_relation() {
+        let mut scenarios = vec![
+            DynRelation::new(Relation::new(1, |x| x * 2)),
+            DynRelation::new(Relation::new(1, |x| x + 3)),
+            DynRelation::new(AffineRelation::new(0, 2, 1)),
+            DynRelation::new(Recurrence::new([1, 1], [0, 1])),
+        ];
+
+        assert_eq!(
+            scenarios
+                .iter_mut()
+                .map(|relation| relation.nth(5))
+                .collect::<Vec<_>>(),
+            [32, 16, 31, 5]
+        );
+
+        let previous = scenarios[0].replace(Relation::new(10, |x| x - 1));
+
+        assert_eq!(*previous.current(), 32);
+
+        assert_eq!(scenarios[0].clone().take(3).collect::<Vec<_>>(), [10, 9, 8]);
+
+        assert_eq!(scenarios[0].index(), 0);
+
+        assert_eq!(RelationLike::collect_terms(&scenarios[1], 2), [16, 19]);
+
+        scenarios.push(DynRelation::new(IndexedRelation::new(0, |n, x| x + n)));
+
+        assert_eq!(scenarios[4].nth(4), 6);
+    }
+}
@@ -0,0 +1,96 @@
+use core::fmt;
+
+use crate::Divergence;
+
+/// The errors that can occur when working with relations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The relation did not converge within the given number of steps
+    DidNotConverge {
+        /// The number of steps that were taken
+        steps: usize,
+    },
+    /// The relation was stopped after reaching the maximum number of steps
+    StepLimitReached {
+        /// The number of steps that were taken
+        steps: usize,
+    },
+    /// The next number could not be calculated, such as from integer overflow
+    Overflow {
+        /// The index of the number which could not be calculated
+        index: usize,
+    },
+    /// A floating point term became `NaN`, infinite, or too large
+    Diverged {
+        /// The index of the term which diverged
+        index: usize,
+        /// Why the term was considered to have diverged
+        reason: Divergence,
+    },
+    /// No closed form could be found for the recurrence
+    NoClosedForm,
+    /// A loan's repayment does not cover its interest, so it would never be repaid
+    InsufficientRepayment,
+    /// No value satisfies the conditions given to a solver
+    NoSolution,
+    /// A row of a transition matrix has a negative entry or does not sum to one
+    NotStochastic {
+        /// The index of the invalid row
+        row: usize,
+    },
+    /// A relation rule could not be parsed
+    InvalidExpression {
+        /// The byte position in the rule where parsing failed
+        position: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DidNotConverge { steps } => {
+                write!(f, "relation did not converge within {steps} steps")
+            }
+            Error::StepLimitReached { steps } => {
+                write!(f, "relation reached the limit of {steps} steps")
+            }
+            Error::Overflow { index } => {
+                write!(
+                    f,
+                    "relation overflowed calculating the number at index {index}"
+                )
+            }
+            Error::Diverged { index, reason } => {
+                let reason = match reason {
+                    Divergence::NaN => "became NaN",
+                    Divergence::Infinite => "became infinite",
+                    Divergence::ExceededThreshold => "exceeded the magnitude threshold",
+                };
+
+                write!(
+                    f,
+                    "relation diverged at index {index}, as the term {reason}"
+                )
+            }
+            Error::NoClosedForm => write!(f, "no closed form could be found for the recurrence"),
+            Error::InsufficientRepayment => {
+                write!(
+                    f,
+                    "the repayment does not cover the interest, so the loan would never be repaid"
+                )
+            }
+            Error::NoSolution => write!(f, "no value satisfies the given conditions"),
+            Error::NotStochastic { row } => {
+                write!(
+                    f,
+                    "row {row} of the transition matrix is not a probability distribution"
+                )
+            }
+            Error::InvalidExpression { position } => {
+                write!(f, "invalid relation expression at position {position}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
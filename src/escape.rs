@@ -0,0 +1,75 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// Numbers with a size which can be compared against a threshold, such as the absolute value or complex modulus
+pub trait Magnitude {
+    /// The type of the magnitude
+    type Output: PartialOrd;
+
+    /// Returns the magnitude of the number
+    fn magnitude(&self) -> Self::Output;
+}
+
+macro_rules! impl_float_magnitude {
+    ($($float:ty),*) => {
+        $(
+            impl Magnitude for $float {
+                type Output = $float;
+
+                fn magnitude(&self) -> Self::Output {
+                    num_traits::Float::abs(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_magnitude!(f32, f64);
+
+impl<I: Num + Clone + Magnitude, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Returns the number of steps from the current number until the magnitude of a term exceeds `threshold`,
+    /// without updating the struct
+    ///
+    /// Returns [`None`] if no term within `max_steps` steps exceeds it.
+    /// With the complex quadratic map, this is the escape time used to colour the Mandelbrot and Julia sets
+    pub fn escape_time(
+        &self,
+        threshold: <I as Magnitude>::Output,
+        max_steps: usize,
+    ) -> Option<usize> {
+        let mut term = self.current_number.clone();
+
+        for step in 0..=max_steps {
+            if term.magnitude() > threshold {
+                return Some(step);
+            }
+
+            if step < max_steps {
+                term = (self.relation)(term);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_time() {
+        let relation = Relation::new(1., |x: f64| x * 2.);
+
+        assert_eq!(relation.escape_time(100., 10), Some(7));
+
+        assert_eq!(relation.escape_time(100., 6), None);
+
+        assert_eq!(relation.escape_time(0.5, 10), Some(0));
+
+        let bounded = Relation::new(-0.5, |x: f64| -x);
+
+        assert_eq!(bounded.escape_time(1., 1000), None);
+    }
+}
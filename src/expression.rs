@@ -0,0 +1,519 @@
+use alloc::boxed::Box;
+
+use num_traits::Float;
+
+use crate::{Error, Relation};
+
+/// A binary operator in an [`Expr`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Operator {
+    /// `a + b`
+    Add,
+    /// `a - b`
+    Subtract,
+    /// `a * b`
+    Multiply,
+    /// `a / b`
+    Divide,
+    /// `a ^ b`
+    Power,
+}
+
+/// A function that can be called in an [`Expr`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Function {
+    /// `abs(a)`
+    Abs,
+    /// `sqrt(a)`
+    Sqrt,
+    /// `exp(a)`
+    Exp,
+    /// `ln(a)`
+    Ln,
+    /// `sin(a)`
+    Sin,
+    /// `cos(a)`
+    Cos,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "abs" => Function::Abs,
+            "sqrt" => Function::Sqrt,
+            "exp" => Function::Exp,
+            "ln" => Function::Ln,
+            "sin" => Function::Sin,
+            "cos" => Function::Cos,
+            _ => return None,
+        })
+    }
+}
+
+/// The syntax tree of a relation rule, such as `2*x + 1`
+///
+/// Rules may refer to the current term as `x` or `t`, and to its index as `n`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr<I> {
+    /// A number literal
+    Number(I),
+    /// The current term
+    Term,
+    /// The index of the current term
+    Index,
+    /// The negation of an expression
+    Negate(Box<Expr<I>>),
+    /// A binary operation on two expressions
+    Binary(Operator, Box<Expr<I>>, Box<Expr<I>>),
+    /// A function applied to an expression
+    Call(Function, Box<Expr<I>>),
+}
+
+impl<I: Float> Expr<I> {
+    /// Parses an expression from a string
+    ///
+    /// Supports `+`, `-`, `*`, `/`, `^` (right associative), parentheses, and the functions
+    /// `abs`, `sqrt`, `exp`, `ln`, `sin`, and `cos`
+    ///
+    /// # Errors
+    /// - If the expression is malformed, the error contains the byte position of the problem
+    /// - If the expression is nested more than 256 levels deep, counting parentheses, calls, and operators
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            source: source.as_bytes(),
+            position: 0,
+            depth: 0,
+        };
+
+        let expression = parser.expression()?;
+
+        parser.skip_whitespace();
+        if parser.position < source.len() {
+            return Err(parser.error());
+        }
+
+        Ok(expression)
+    }
+
+    /// Evaluates the expression for the given term and index
+    pub fn evaluate(&self, term: I, index: usize) -> I {
+        match self {
+            Expr::Number(number) => *number,
+            Expr::Term => term,
+            Expr::Index => I::from(index).unwrap_or_else(I::nan),
+            Expr::Negate(inner) => -inner.evaluate(term, index),
+            Expr::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.evaluate(term, index);
+                let rhs = rhs.evaluate(term, index);
+
+                match operator {
+                    Operator::Add => lhs + rhs,
+                    Operator::Subtract => lhs - rhs,
+                    Operator::Multiply => lhs * rhs,
+                    Operator::Divide => lhs / rhs,
+                    Operator::Power => lhs.powf(rhs),
+                }
+            }
+            Expr::Call(function, inner) => {
+                let inner = inner.evaluate(term, index);
+
+                match function {
+                    Function::Abs => inner.abs(),
+                    Function::Sqrt => inner.sqrt(),
+                    Function::Exp => inner.exp(),
+                    Function::Ln => inner.ln(),
+                    Function::Sin => inner.sin(),
+                    Function::Cos => inner.cos(),
+                }
+            }
+        }
+    }
+}
+
+/// The deepest an [`Expr`] can be nested, so parsing and evaluating untrusted rules cannot overflow the stack
+const MAX_DEPTH: usize = 256;
+
+/// A recursive descent parser for [`Expr`]
+struct Parser<'a> {
+    source: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> Error {
+        Error::InvalidExpression {
+            position: self.position,
+        }
+    }
+
+    /// Goes one level deeper into the syntax tree, failing past [`MAX_DEPTH`]
+    fn nest(&mut self) -> Result<(), Error> {
+        self.depth += 1;
+
+        if self.depth > MAX_DEPTH {
+            Err(self.error())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .source
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.source.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &str {
+        let start = self.position;
+        while self
+            .source
+            .get(self.position)
+            .is_some_and(|&b| predicate(b))
+        {
+            self.position += 1;
+        }
+
+        // Only ASCII bytes are accepted by the predicates, so this is always valid UTF-8
+        core::str::from_utf8(&self.source[start..self.position]).unwrap_or_default()
+    }
+
+    /// `expression := product (('+' | '-') product)*`
+    fn expression<I: Float>(&mut self) -> Result<Expr<I>, Error> {
+        let depth = self.depth;
+        let mut lhs = self.product()?;
+
+        loop {
+            let operator = if self.eat(b'+') {
+                Operator::Add
+            } else if self.eat(b'-') {
+                Operator::Subtract
+            } else {
+                self.depth = depth;
+                return Ok(lhs);
+            };
+
+            // Each operator nests the terms before it one level deeper
+            self.nest()?;
+            lhs = Expr::Binary(operator, Box::new(lhs), Box::new(self.product()?));
+        }
+    }
+
+    /// `product := unary (('*' | '/') unary)*`
+    fn product<I: Float>(&mut self) -> Result<Expr<I>, Error> {
+        let depth = self.depth;
+        let mut lhs = self.unary()?;
+
+        loop {
+            let operator = if self.eat(b'*') {
+                Operator::Multiply
+            } else if self.eat(b'/') {
+                Operator::Divide
+            } else {
+                self.depth = depth;
+                return Ok(lhs);
+            };
+
+            // Each operator nests the terms before it one level deeper
+            self.nest()?;
+            lhs = Expr::Binary(operator, Box::new(lhs), Box::new(self.unary()?));
+        }
+    }
+
+    /// `unary := '-' unary | power`
+    fn unary<I: Float>(&mut self) -> Result<Expr<I>, Error> {
+        if self.eat(b'-') {
+            self.nest()?;
+            let inner = self.unary()?;
+            self.depth -= 1;
+
+            Ok(Expr::Negate(Box::new(inner)))
+        } else {
+            self.power()
+        }
+    }
+
+    /// `power := atom ('^' unary)?`
+    fn power<I: Float>(&mut self) -> Result<Expr<I>, Error> {
+        let base = self.atom()?;
+
+        if self.eat(b'^') {
+            self.nest()?;
+            let exponent = self.unary()?;
+            self.depth -= 1;
+
+            Ok(Expr::Binary(
+                Operator::Power,
+                Box::new(base),
+                Box::new(exponent),
+            ))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// `atom := number | variable | function '(' expression ')' | '(' expression ')'`
+    fn atom<I: Float>(&mut self) -> Result<Expr<I>, Error> {
+        let start = self.position;
+
+        match self.peek() {
+            Some(b'(') => {
+                self.position += 1;
+                self.nest()?;
+                let inner = self.expression()?;
+                self.depth -= 1;
+
+                if self.eat(b')') {
+                    Ok(inner)
+                } else {
+                    Err(self.error())
+                }
+            }
+            Some(b'0'..=b'9' | b'.') => {
+                let literal = self.take_while(|b| b.is_ascii_digit() || b == b'.');
+
+                I::from_str_radix(literal, 10)
+                    .map(Expr::Number)
+                    .map_err(|_| Error::InvalidExpression { position: start })
+            }
+            Some(b'a'..=b'z' | b'A'..=b'Z') => match self.take_while(|b| b.is_ascii_alphabetic()) {
+                "x" | "t" => Ok(Expr::Term),
+                "n" => Ok(Expr::Index),
+                name => {
+                    let function = Function::from_name(name)
+                        .ok_or(Error::InvalidExpression { position: start })?;
+
+                    if !self.eat(b'(') {
+                        return Err(self.error());
+                    }
+
+                    self.nest()?;
+                    let inner = self.expression()?;
+                    self.depth -= 1;
+
+                    if self.eat(b')') {
+                        Ok(Expr::Call(function, Box::new(inner)))
+                    } else {
+                        Err(self.error())
+                    }
+                }
+            },
+            _ => Err(self.error()),
+        }
+    }
+}
+
+/// A relation whose rule is an [`Expr`] rather than a closure
+///
+/// This allows rules to be read from configuration files or user input
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExpressionRelation<I> {
+    current: I,
+    index: usize,
+    expression: Expr<I>,
+}
+
+impl<I: Float> ExpressionRelation<I> {
+    /// Create a new [`ExpressionRelation`]
+    pub fn new(expression: Expr<I>, starter: I) -> Self {
+        Self {
+            current: starter,
+            index: 0,
+            expression,
+        }
+    }
+
+    /// Create a new [`ExpressionRelation`] by parsing the rule from a string
+    ///
+    /// See [`Expr::parse`] for the supported syntax
+    ///
+    /// # Errors
+    /// - If the rule is not a valid expression
+    pub fn parse(source: &str, starter: I) -> Result<Self, Error> {
+        Ok(Self::new(Expr::parse(source)?, starter))
+    }
+
+    /// Returns the rule of the relation
+    pub fn expression(&self) -> &Expr<I> {
+        &self.expression
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        self.expression.evaluate(self.current, self.index)
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`ExpressionRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current
+    }
+}
+
+impl<I: Float> Iterator for ExpressionRelation<I> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current;
+        ExpressionRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Float> Relation<I, ()> {
+    /// Create a new [`ExpressionRelation`] by parsing the rule from a string
+    ///
+    /// See [`Expr::parse`] for the supported syntax
+    ///
+    /// # Errors
+    /// - If the rule is not a valid expression
+    pub fn parse(source: &str, starter: I) -> Result<ExpressionRelation<I>, Error> {
+        ExpressionRelation::parse(source, starter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_relation() {
+        let mut relation = Relation::parse("2*x + 1", 5.).unwrap();
+
+        assert_eq!(relation.calculate_next(), 11.);
+
+        relation.next();
+
+        assert_eq!(relation.current, 11.);
+
+        assert_eq!(relation.nth(2), 47.);
+    }
+
+    #[test]
+    fn test_precedence() {
+        let expression = Expr::<f64>::parse("-x^2 + 3 * (n - 1) / 2 ^ 2 ^ -1").unwrap();
+
+        let expected = -(4f64) + 3. * (5. - 1.) / 2f64.sqrt();
+
+        assert!((expression.evaluate(2., 5) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_functions() {
+        let relation = ExpressionRelation::parse("sqrt(abs(t)) + ln(exp(n))", 16.).unwrap();
+
+        assert!(relation.take(3).eq([16., 4., 3.]));
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert_eq!(
+            Expr::<f64>::parse("2 * "),
+            Err(Error::InvalidExpression { position: 4 })
+        );
+
+        assert_eq!(
+            Expr::<f64>::parse("(x + 1"),
+            Err(Error::InvalidExpression { position: 6 })
+        );
+
+        assert_eq!(
+            Expr::<f64>::parse("foo(x)"),
+            Err(Error::InvalidExpression { position: 0 })
+        );
+
+        assert_eq!(
+            Expr::<f64>::parse("x 1"),
+            Err(Error::InvalidExpression { position: 2 })
+        );
+
+        assert_eq!(
+            Expr::<f64>::parse("1.2.3"),
+            Err(Error::InvalidExpression { position: 0 })
+        );
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |prefix: &str, count: usize| prefix.repeat(count) + "x" + &")".repeat(count);
+
+        assert!(Expr::<f64>::parse(&nested("(", 200)).is_ok());
+
+        assert_eq!(
+            Expr::<f64>::parse(&nested("(", 50_000)),
+            Err(Error::InvalidExpression { position: 257 })
+        );
+
+        assert!(matches!(
+            Expr::<f64>::parse(&"-".repeat(50_000)),
+            Err(Error::InvalidExpression { .. })
+        ));
+
+        assert!(matches!(
+            Expr::<f64>::parse(&["x"; 50_000].join("+")),
+            Err(Error::InvalidExpression { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_expression_round_trip() {
+        let relation = ExpressionRelation::parse("x / 2", 1.).unwrap();
+
+        let json = serde_json::to_string(&relation).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"current":1.0,"index":0,"expression":{"Binary":["Divide","Term",{"Number":2.0}]}}"#
+        );
+
+        let resumed: ExpressionRelation<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(resumed, relation);
+    }
+}
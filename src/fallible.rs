@@ -0,0 +1,161 @@
+use num_traits::Num;
+
+/// A relation whose relation function returns a [`Result`]
+///
+/// This allows domain errors, such as dividing by zero, to stop the sequence cleanly
+/// instead of producing `NaN` or panicking
+#[derive(Debug, Copy, Clone)]
+pub struct TryRelation<I, F, E> {
+    current_number: I,
+    index: usize,
+    relation: F,
+    progress: Progress<E>,
+}
+
+/// How far through the sequence a [`TryRelation`] iterator is
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Progress<E> {
+    /// Every number so far has been calculated
+    Running,
+    /// The number after the current number could not be calculated, with the error yet to be yielded
+    Failed(E),
+    /// The error has been yielded, and the iterator has ended
+    Finished,
+}
+
+impl<I: Num + Clone, E, F: FnOnce(I) -> Result<I, E> + Copy> TryRelation<I, F, E> {
+    /// Create a new [`TryRelation`]
+    pub fn new(starter: I, relation: F) -> Self {
+        Self {
+            current_number: starter,
+            index: 0,
+            relation,
+            progress: Progress::Running,
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    ///
+    /// # Errors
+    /// - If the relation function returns an error
+    pub fn calculate_next(&self) -> Result<I, E> {
+        (self.relation)(self.current_number.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    ///
+    /// # Errors
+    /// - If the relation function returns an error, in which case the struct is not updated
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<(), E> {
+        self.current_number = self.calculate_next()?;
+        self.index += 1;
+
+        Ok(())
+    }
+
+    /// Calls [`TryRelation::next`] `index` times, and then returns the result
+    ///
+    /// # Errors
+    /// - If the relation function returns an error, in which case the struct is left at the last number before it
+    pub fn nth(&mut self, index: usize) -> Result<I, E> {
+        for _ in 0..index {
+            self.next()?;
+        }
+
+        Ok(self.current_number.clone())
+    }
+}
+
+impl<I: Num + Clone, E, F: FnOnce(I) -> Result<I, E> + Copy> Iterator for TryRelation<I, F, E> {
+    type Item = Result<I, E>;
+
+    /// Yields the current number and then advances the relation
+    ///
+    /// Once the relation function returns an error, that error is yielded and the iterator ends
+    fn next(&mut self) -> Option<Self::Item> {
+        match core::mem::replace(&mut self.progress, Progress::Finished) {
+            Progress::Running => {
+                let current = self.current_number.clone();
+
+                self.progress = match TryRelation::next(self) {
+                    Ok(()) => Progress::Running,
+                    Err(error) => Progress::Failed(error),
+                };
+
+                Some(Ok(current))
+            }
+            Progress::Failed(error) => Some(Err(error)),
+            Progress::Finished => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct DivideByZero;
+
+    fn halve_until_zero(x: i32) -> Result<i32, DivideByZero> {
+        if x == 0 {
+            Err(DivideByZero)
+        } else {
+            Ok(x / 2)
+        }
+    }
+
+    #[test]
+    fn test_try_relation() {
+        let mut relation = TryRelation::new(100, halve_until_zero);
+
+        assert_eq!(relation.calculate_next(), Ok(50));
+
+        assert_eq!(relation.nth(3), Ok(12));
+
+        assert_eq!(relation.nth(10), Err(DivideByZero));
+
+        assert_eq!(relation.current(), &0);
+
+        assert_eq!(relation.index(), 7);
+    }
+
+    #[test]
+    fn test_try_iterator() {
+        let relation = TryRelation::new(10, halve_until_zero);
+
+        assert!(relation.eq([Ok(10), Ok(5), Ok(2), Ok(1), Ok(0), Err(DivideByZero)]));
+
+        let total: Result<i32, _> = TryRelation::new(3, halve_until_zero).sum();
+
+        assert_eq!(total, Err(DivideByZero));
+
+        let total: Result<i32, _> = TryRelation::new(3, halve_until_zero).take(3).sum();
+
+        assert_eq!(total, Ok(4));
+    }
+
+    #[test]
+    fn test_rule_called_once_per_term() {
+        let calls = core::cell::Cell::new(0);
+        let relation = TryRelation::new(4, |x| {
+            calls.set(calls.get() + 1);
+            halve_until_zero(x)
+        });
+
+        assert_eq!(relation.count(), 5);
+
+        assert_eq!(calls.get(), 4);
+    }
+}
@@ -0,0 +1,116 @@
+//! A C API over the affine relations of the finance module, through an opaque handle
+//!
+//! Build a shared library with `cargo rustc --lib --release --features ffi --crate-type cdylib`,
+//! and include `include/relation.h`. Every handle returned by a constructor must be released with [`relation_free`]
+
+use alloc::boxed::Box;
+
+use crate::{finance, AffineRelation};
+
+/// An opaque handle to a relation of `double` terms, owned by the caller
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelationHandle {
+    relation: AffineRelation<f64>,
+}
+
+/// Moves `relation` onto the heap, handing ownership to the caller
+fn into_handle(relation: AffineRelation<f64>) -> Box<RelationHandle> {
+    Box::new(RelationHandle { relation })
+}
+
+/// Creates a relation where `t(n+1) = multiplier * t(n) + increment`
+#[no_mangle]
+pub extern "C" fn relation_new_affine(
+    starter: f64,
+    multiplier: f64,
+    increment: f64,
+) -> Box<RelationHandle> {
+    into_handle(AffineRelation::new(starter, multiplier, increment))
+}
+
+/// Creates a relation for a balance earning compound interest, like [`finance::compound_interest`]
+#[no_mangle]
+pub extern "C" fn relation_new_compound_interest(
+    principal: f64,
+    rate_per_period: f64,
+) -> Box<RelationHandle> {
+    into_handle(finance::compound_interest(principal, rate_per_period))
+}
+
+/// Creates a relation for an ordinary annuity, like [`finance::annuity`]
+#[no_mangle]
+pub extern "C" fn relation_new_annuity(start: f64, rate: f64, payment: f64) -> Box<RelationHandle> {
+    into_handle(finance::annuity(start, rate, payment))
+}
+
+/// Creates a relation for an annuity due, like [`finance::annuity_due`]
+#[no_mangle]
+pub extern "C" fn relation_new_annuity_due(
+    start: f64,
+    rate: f64,
+    payment: f64,
+) -> Box<RelationHandle> {
+    into_handle(finance::annuity_due(start, rate, payment))
+}
+
+/// Returns the current number, or `NaN` if `handle` is null
+#[no_mangle]
+pub extern "C" fn relation_current(handle: Option<&RelationHandle>) -> f64 {
+    handle.map_or(f64::NAN, |handle| *handle.relation.current())
+}
+
+/// Returns the index of the current number, or `0` if `handle` is null
+#[no_mangle]
+pub extern "C" fn relation_index(handle: Option<&RelationHandle>) -> usize {
+    handle.map_or(0, |handle| handle.relation.index())
+}
+
+/// Advances the relation by one step and returns the new current number, or `NaN` if `handle` is null
+#[no_mangle]
+pub extern "C" fn relation_next(handle: Option<&mut RelationHandle>) -> f64 {
+    handle.map_or(f64::NAN, |handle| {
+        handle.relation.next();
+
+        *handle.relation.current()
+    })
+}
+
+/// Advances the relation by `index` steps and returns the new current number, or `NaN` if `handle` is null
+#[no_mangle]
+pub extern "C" fn relation_nth(handle: Option<&mut RelationHandle>, index: usize) -> f64 {
+    handle.map_or(f64::NAN, |handle| handle.relation.nth(index))
+}
+
+/// Releases a handle returned by one of the constructors, doing nothing if `handle` is null
+///
+/// The handle must not be used again afterwards
+#[no_mangle]
+pub extern "C" fn relation_free(handle: Option<Box<RelationHandle>>) {
+    drop(handle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi() {
+        let mut handle = relation_new_annuity(0., 0.05, 100.);
+
+        assert_eq!(relation_next(Some(&mut handle)), 100.);
+
+        assert_eq!(relation_nth(Some(&mut handle), 1), 205.);
+
+        assert_eq!(relation_index(Some(&handle)), 2);
+
+        assert_eq!(relation_current(Some(&handle)), 205.);
+
+        relation_free(Some(handle));
+
+        assert!(relation_next(None).is_nan());
+
+        assert_eq!(relation_index(None), 0);
+
+        relation_free(None);
+    }
+}
@@ -0,0 +1,438 @@
+//! Ready-made relations for financial modelling
+//!
+//! Rates are given as fractions per period, so an interest rate of 5% per period is `0.05`
+
+use num_traits::{Float, Num};
+
+use crate::{AffineRelation, Error, Relation};
+
+mod growing;
+mod inflation;
+mod loan;
+#[cfg(feature = "rand")]
+mod random;
+
+pub use growing::GrowingAnnuity;
+pub use inflation::{inflation_adjusted, Adjusted, InflationAdjusted};
+pub use loan::{AmortizationRow, Loan, Savings};
+#[cfg(feature = "rand")]
+pub use random::{geometric_brownian_motion, random_walk};
+
+/// Create a relation for a balance earning compound interest, where `V(n+1) = V(n) * (1 + rate)`
+pub fn compound_interest<I: Num + Clone>(principal: I, rate_per_period: I) -> AffineRelation<I> {
+    AffineRelation::linear(principal, I::one() + rate_per_period)
+}
+
+/// Create a relation for an ordinary annuity, where a payment is added at the end of each period
+///
+/// This is `V(n+1) = V(n) * (1 + rate) + payment`, which models savings plans and superannuation contributions.
+/// A negative payment models regular withdrawals, or loan repayments when the balance is the amount owed
+pub fn annuity<I: Num + Clone>(start: I, rate: I, payment: I) -> AffineRelation<I> {
+    AffineRelation::new(start, I::one() + rate, payment)
+}
+
+/// Create a relation for an annuity due, where a payment is added at the start of each period
+///
+/// This is `V(n+1) = (V(n) + payment) * (1 + rate)`, so each payment earns one more period of interest than in [`annuity`]
+pub fn annuity_due<I: Num + Clone>(start: I, rate: I, payment: I) -> AffineRelation<I> {
+    let growth = I::one() + rate;
+
+    AffineRelation::new(start, growth.clone(), payment * growth)
+}
+
+/// Create a relation for the book value of an asset under declining balance depreciation, where `V(n+1) = V(n) * (1 - rate)`
+pub fn declining_balance<I: Num + Clone>(cost: I, rate: I) -> AffineRelation<I> {
+    AffineRelation::linear(cost, I::one() - rate)
+}
+
+/// Create a relation for the book value of an asset under straight line depreciation
+///
+/// The value falls by `(cost - salvage) / life` each period, until it reaches the salvage value after `life` periods,
+/// where it then stays
+pub fn straight_line<I: Float>(
+    cost: I,
+    salvage: I,
+    life: u32,
+) -> Relation<I, impl FnOnce(I) -> I + Copy> {
+    let depreciation = (cost - salvage) / I::from(life).unwrap_or_else(I::nan);
+
+    Relation::new(cost, move |value: I| (value - depreciation).max(salvage))
+}
+
+/// Finds the value after `periods` periods of a starting balance, with a payment added at the end of each period
+///
+/// This steps [`annuity`] in `O(log n)`, so it works for any number type, including exact ones such as rationals
+pub fn future_value<I: Num + Clone>(present: I, rate: I, payment: I, periods: u32) -> I {
+    annuity(present, rate, payment).nth_closed_form(periods as usize)
+}
+
+/// Finds the value today of a lump sum received after `periods` periods, plus a payment received at the end of each period
+///
+/// This discounts the cash flows with a relation stepping backwards from the last period, so it works for any number type
+pub fn present_value<I: Num + Clone>(future: I, rate: I, payment: I, periods: u32) -> I {
+    let discount = I::one() / (I::one() + rate);
+
+    AffineRelation::new(future, discount.clone(), payment * discount)
+        .nth_closed_form(periods as usize)
+}
+
+/// Finds the same value as [`future_value`], using the closed form `V * (1 + r)^n + P * ((1 + r)^n - 1) / r`
+pub fn future_value_closed_form<I: Float>(present: I, rate: I, payment: I, periods: u32) -> I {
+    let periods = I::from(periods).unwrap_or_else(I::nan);
+
+    if rate.is_zero() {
+        return present + payment * periods;
+    }
+
+    let growth = (periods * rate.ln_1p()).exp_m1();
+
+    present * (growth + I::one()) + payment * growth / rate
+}
+
+/// Finds the same value as [`present_value`], using the closed form `F * (1 + r)^-n + P * (1 - (1 + r)^-n) / r`
+pub fn present_value_closed_form<I: Float>(future: I, rate: I, payment: I, periods: u32) -> I {
+    let periods = I::from(periods).unwrap_or_else(I::nan);
+
+    if rate.is_zero() {
+        return future + payment * periods;
+    }
+
+    let discount = (-periods * rate.ln_1p()).exp_m1();
+
+    future * (discount + I::one()) - payment * discount / rate
+}
+
+/// Returns the first period at which a sequence of book values falls below `scrap_value`
+///
+/// Returns [`None`] if it does not happen within `max_periods` periods
+pub fn periods_until_below<I: PartialOrd>(
+    book_values: impl IntoIterator<Item = I>,
+    scrap_value: I,
+    max_periods: usize,
+) -> Option<usize> {
+    book_values
+        .into_iter()
+        .take(max_periods.saturating_add(1))
+        .position(|value| value < scrap_value)
+}
+
+/// Finds the repayment at the end of each period which repays a loan in exactly `periods` periods
+///
+/// Uses the closed form of the annuity, `P = L * r / (1 - (1 + r)^-n)`
+pub fn solve_payment<I: Float>(principal: I, rate: I, periods: u32) -> I {
+    let periods = I::from(periods).unwrap_or_else(I::nan);
+
+    if rate.is_zero() {
+        return principal / periods;
+    }
+
+    principal * rate / -(-periods * rate.ln_1p()).exp_m1()
+}
+
+/// Finds the repayment which brings a balance to zero in exactly `periods` periods, using bisection
+///
+/// This handles loan structures without a closed form, such as fees or offset accounts.
+/// `step` calculates the next balance from the current balance and the repayment,
+/// and the final balance must decrease as the repayment increases
+///
+/// # Errors
+/// - If no repayment brings the final balance within `tolerance` of zero, within `max_steps` bisection steps
+pub fn solve_payment_numeric<I: Float>(
+    principal: I,
+    periods: u32,
+    step: impl Fn(I, I) -> I,
+    tolerance: I,
+    max_steps: usize,
+) -> Result<I, Error> {
+    let final_balance =
+        |payment: I| (0..periods).fold(principal, |balance, _| step(balance, payment));
+
+    // Widen the upper bound until it overpays the loan
+    let mut low = I::zero();
+    let mut high = principal.abs().max(I::one());
+    let mut steps = 0;
+
+    while final_balance(high) > I::zero() {
+        if steps >= max_steps {
+            return Err(Error::DidNotConverge { steps });
+        }
+
+        low = high;
+        high = high + high;
+        steps += 1;
+    }
+
+    while steps < max_steps {
+        let payment = (low + high) / (I::one() + I::one());
+        let balance = final_balance(payment);
+
+        if balance.abs() <= tolerance {
+            return Ok(payment);
+        }
+
+        if balance > I::zero() {
+            low = payment;
+        } else {
+            high = payment;
+        }
+
+        steps += 1;
+    }
+
+    Err(Error::DidNotConverge { steps })
+}
+
+/// Finds the rate per period at which `present` grows to `future` in `periods` periods,
+/// with a payment added at the end of each period, using bisection over [`future_value`]
+///
+/// This answers what return an investment achieved. The final value must increase with the rate,
+/// which holds when the starting value and payments are not negative
+///
+/// # Errors
+/// - If `future` is below the value reached with a rate of -100%, as no rate reaches it
+/// - If no rate brings the final value within `tolerance` of `future`, within `max_steps` bisection steps
+pub fn solve_rate<I: Float>(
+    present: I,
+    future: I,
+    payment: I,
+    periods: u32,
+    tolerance: I,
+    max_steps: usize,
+) -> Result<I, Error> {
+    let shortfall = |rate: I| future_value(present, rate, payment, periods) - future;
+
+    // A rate of -100% loses everything but the last payment
+    let mut low = -I::one();
+
+    if shortfall(low) > tolerance {
+        return Err(Error::NoSolution);
+    }
+
+    // Widen the upper bound until it overshoots the target
+    let mut high = I::one();
+    let mut steps = 0;
+
+    while shortfall(high) < I::zero() {
+        if steps >= max_steps {
+            return Err(Error::DidNotConverge { steps });
+        }
+
+        low = high;
+        high = high + high;
+        steps += 1;
+    }
+
+    while steps < max_steps {
+        let rate = (low + high) / (I::one() + I::one());
+        let shortfall = shortfall(rate);
+
+        if shortfall.abs() <= tolerance {
+            return Ok(rate);
+        }
+
+        if shortfall < I::zero() {
+            low = rate;
+        } else {
+            high = rate;
+        }
+
+        steps += 1;
+    }
+
+    Err(Error::DidNotConverge { steps })
+}
+
+/// Converts a nominal annual rate, compounded `periods_per_year` times a year, to the effective annual rate
+pub fn effective_rate<I: Float>(nominal_rate: I, periods_per_year: u32) -> I {
+    let periods = I::from(periods_per_year).unwrap_or_else(I::nan);
+
+    ((nominal_rate / periods).ln_1p() * periods).exp_m1()
+}
+
+/// Converts an effective annual rate to the nominal annual rate, compounded `periods_per_year` times a year
+pub fn nominal_rate<I: Float>(effective_rate: I, periods_per_year: u32) -> I {
+    let periods = I::from(periods_per_year).unwrap_or_else(I::nan);
+
+    (effective_rate.ln_1p() / periods).exp_m1() * periods
+}
+
+/// Converts a nominal annual rate, compounded continuously, to the effective annual rate
+pub fn continuous_effective_rate<I: Float>(nominal_rate: I) -> I {
+    nominal_rate.exp_m1()
+}
+
+/// Converts an effective annual rate to the equivalent effective rate per period, for `periods_per_year` periods a year
+pub fn periodic_rate<I: Float>(effective_rate: I, periods_per_year: u32) -> I {
+    nominal_rate(effective_rate, periods_per_year)
+        / I::from(periods_per_year).unwrap_or_else(I::nan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-10, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_compound_interest() {
+        let mut balance = compound_interest(1000., 0.05);
+
+        assert_eq!(balance.calculate_next(), 1050.);
+
+        assert_close(balance.nth_closed_form(10), 1000. * 1.05f64.powi(10));
+
+        assert_close(balance.nth(10), 1628.894626777442);
+
+        let mut exact = compound_interest(100, 1);
+
+        assert_eq!(exact.nth(3), 800);
+    }
+
+    #[test]
+    fn test_annuities() {
+        let ordinary = annuity(0., 0.05, 100.);
+
+        assert!(ordinary.take(3).eq([0., 100., 205.]));
+
+        // The future value of an ordinary annuity is P * ((1 + r)^n - 1) / r
+        assert_close(
+            ordinary.nth_closed_form(10),
+            100. * (1.05f64.powi(10) - 1.) / 0.05,
+        );
+
+        let due = annuity_due(0., 0.05, 100.);
+
+        assert!(due.take(3).eq([0., 105., 215.25]));
+
+        assert_close(due.nth_closed_form(10), ordinary.nth_closed_form(10) * 1.05);
+    }
+
+    #[test]
+    fn test_time_value() {
+        assert_close(future_value(1000., 0.05, 0., 10), 1000. * 1.05f64.powi(10));
+
+        assert_close(present_value(1000. * 1.05f64.powi(10), 0.05, 0., 10), 1000.);
+
+        assert_close(
+            future_value(500., 0.01, 100., 24),
+            future_value_closed_form(500., 0.01, 100., 24),
+        );
+
+        assert_close(
+            present_value(500., 0.01, 100., 24),
+            present_value_closed_form(500., 0.01, 100., 24),
+        );
+
+        // The present value of the repayments is the amount borrowed
+        let payment = solve_payment(10_000., 0.01, 24);
+
+        assert_close(present_value_closed_form(0., 0.01, payment, 24), 10_000.);
+
+        assert_close(future_value_closed_form(100., 0., 10., 5), 150.);
+
+        assert_close(present_value_closed_form(100., 0., 10., 5), 150.);
+
+        assert_eq!(future_value(100, 1, 0, 3), 800);
+    }
+
+    #[test]
+    fn test_depreciation() {
+        let declining = declining_balance(10_000., 0.2);
+
+        assert!(declining.take(3).eq([10_000., 8000., 6400.]));
+
+        assert_eq!(periods_until_below(declining, 1000., 100), Some(11));
+
+        let straight = straight_line(10_000., 1000., 3);
+
+        assert!(straight.take(5).eq([10_000., 7000., 4000., 1000., 1000.]));
+
+        assert_eq!(periods_until_below(straight, 5000., 100), Some(2));
+
+        assert_eq!(periods_until_below(straight, 500., 100), None);
+    }
+
+    #[test]
+    fn test_solve_payment() {
+        // A $300,000 mortgage over 30 years at 6% a year, compounded monthly
+        let payment = solve_payment(300_000., 0.005, 360);
+
+        assert!((payment - 1798.65).abs() < 0.01);
+
+        let mut balance = Loan::new(300_000., 0.005, payment).balance();
+
+        assert!(balance.nth(360).abs() < 1e-6);
+
+        assert_close(solve_payment(1200., 0., 12), 100.);
+    }
+
+    #[test]
+    fn test_solve_payment_numeric() {
+        let closed_form = solve_payment(10_000., 0.01, 24);
+        let numeric = solve_payment_numeric(
+            10_000.,
+            24,
+            |balance, payment| balance * 1.01 - payment,
+            1e-9,
+            200,
+        )
+        .unwrap();
+
+        assert!((numeric - closed_form).abs() < 1e-8);
+
+        // A $10 fee each period costs exactly $10 more per repayment
+        let with_fee = solve_payment_numeric(
+            10_000.,
+            24,
+            |balance, payment| balance * 1.01 + 10. - payment,
+            1e-9,
+            200,
+        )
+        .unwrap();
+
+        assert!((with_fee - closed_form - 10.).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_solve_rate() {
+        let rate = solve_rate(1000., 1000. * 1.07f64.powi(10), 0., 10, 1e-9, 200).unwrap();
+
+        assert!((rate - 0.07).abs() < 1e-10);
+
+        let target = future_value(500., 0.01, 100., 24);
+        let rate = solve_rate(500., target, 100., 24, 1e-9, 200).unwrap();
+
+        assert!((rate - 0.01).abs() < 1e-10);
+
+        // Losing money is a negative return
+        let rate = solve_rate(1000., 500., 0., 1, 1e-9, 200).unwrap();
+
+        assert!((rate + 0.5).abs() < 1e-10);
+
+        assert_eq!(
+            solve_rate(1000., 50., 100., 5, 1e-9, 200),
+            Err(Error::NoSolution)
+        );
+
+        assert_eq!(
+            solve_rate(1000., 1e300, 0., 1, 1e-9, 10),
+            Err(Error::DidNotConverge { steps: 10 })
+        );
+    }
+
+    #[test]
+    fn test_rate_conversions() {
+        // 12% compounded monthly
+        assert_close(effective_rate(0.12, 12), 1.01f64.powi(12) - 1.);
+
+        assert_close(nominal_rate(effective_rate(0.12, 12), 12), 0.12);
+
+        assert_close(periodic_rate(effective_rate(0.12, 12), 12), 0.01);
+
+        assert_close(effective_rate(0.05, 1), 0.05);
+
+        assert_close(continuous_effective_rate(0.05), 0.05f64.exp() - 1.);
+    }
+}
@@ -0,0 +1,161 @@
+use num_traits::{Float, Num};
+
+/// A balance which compounds while receiving a contribution that itself grows each period,
+/// where `B(n+1) = B(n) * (1 + rate) + C * (1 + growth)^n`
+///
+/// This models retirement savings where contributions rise with salary.
+/// It is built up from [`GrowingAnnuity::new`], and has no interest or contributions until they are added
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrowingAnnuity<I> {
+    current: I,
+    contribution: I,
+    index: usize,
+    growth: I,
+    contribution_growth: I,
+}
+
+impl<I: Num + Clone> GrowingAnnuity<I> {
+    /// Create a new [`GrowingAnnuity`] from the starting balance
+    pub fn new(start: I) -> Self {
+        Self {
+            current: start,
+            contribution: I::zero(),
+            index: 0,
+            growth: I::one(),
+            contribution_growth: I::one(),
+        }
+    }
+
+    /// Sets the interest rate earned by the balance each period
+    pub fn with_rate(self, rate: I) -> Self {
+        Self {
+            growth: I::one() + rate,
+            ..self
+        }
+    }
+
+    /// Sets the contribution added at the end of the next period
+    pub fn with_contribution(self, contribution: I) -> Self {
+        Self {
+            contribution,
+            ..self
+        }
+    }
+
+    /// Sets the rate the contribution grows by each period, such as salary growth
+    pub fn with_contribution_growth(self, growth: I) -> Self {
+        Self {
+            contribution_growth: I::one() + growth,
+            ..self
+        }
+    }
+
+    /// Returns the current balance
+    pub fn current(&self) -> &I {
+        &self.current
+    }
+
+    /// Returns the contribution which will be added at the end of the next period
+    pub fn contribution(&self) -> &I {
+        &self.contribution
+    }
+
+    /// Returns the index of the current balance, where the starting balance is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next balance, without updating the current balance
+    pub fn calculate_next(&self) -> I {
+        self.current.clone() * self.growth.clone() + self.contribution.clone()
+    }
+
+    /// Calculates the next balance and contribution, and updates the struct
+    pub fn next(&mut self) {
+        self.current = self.calculate_next();
+        self.contribution = self.contribution.clone() * self.contribution_growth.clone();
+        self.index += 1;
+    }
+
+    /// Calls [`GrowingAnnuity::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current.clone()
+    }
+}
+
+impl<I: Float> GrowingAnnuity<I> {
+    /// Returns the balance `index` steps after the current balance, without stepping through the ones in between
+    ///
+    /// Uses the closed form `B * g^n + C * (g^n - h^n) / (g - h)`, where `g` is the balance growth and `h` the contribution growth,
+    /// or `B * g^n + C * n * g^(n-1)` when they are equal
+    pub fn nth_closed_form(&self, index: usize) -> I {
+        let periods = I::from(index).unwrap_or_else(I::nan);
+        let growth = self.growth.powf(periods);
+
+        let contributions = if self.growth == self.contribution_growth {
+            periods * self.growth.powf(periods - I::one())
+        } else {
+            (growth - self.contribution_growth.powf(periods))
+                / (self.growth - self.contribution_growth)
+        };
+
+        self.current * growth + self.contribution * contributions
+    }
+}
+
+impl<I: Num + Clone> Iterator for GrowingAnnuity<I> {
+    type Item = I;
+
+    /// Yields the current balance and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.clone();
+        GrowingAnnuity::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_growing_contributions() {
+        let projection = GrowingAnnuity::new(1000)
+            .with_rate(1)
+            .with_contribution(10)
+            .with_contribution_growth(2);
+
+        assert!(projection.take(4).eq([1000, 2010, 4050, 8190]));
+
+        let mut projection = projection;
+
+        assert_eq!(projection.nth(2), 4050);
+
+        assert_eq!(projection.contribution(), &90);
+    }
+
+    #[test]
+    fn test_closed_form() {
+        let projection = GrowingAnnuity::new(50_000.)
+            .with_rate(0.07)
+            .with_contribution(5000.)
+            .with_contribution_growth(0.03);
+
+        let stepped = projection.clone().nth(40);
+
+        assert!((projection.nth_closed_form(40) - stepped).abs() < 1e-6);
+
+        let equal = projection.with_contribution_growth(0.07);
+
+        assert!((equal.nth_closed_form(40) - equal.clone().nth(40)).abs() < 1e-6);
+    }
+}
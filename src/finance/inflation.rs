@@ -0,0 +1,95 @@
+use num_traits::Num;
+
+/// A term of a financial relation, in both nominal and real terms
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adjusted<I> {
+    /// The value in the money of the period it occurs
+    pub nominal: I,
+    /// The value in the money of the first period, which is `nominal / (1 + inflation)^n`
+    pub real: I,
+}
+
+/// An iterator over the terms of a relation, alongside their inflation adjusted values
+///
+/// Created by [`inflation_adjusted`]
+#[derive(Debug, Clone)]
+pub struct InflationAdjusted<R, I> {
+    terms: R,
+    inflation_factor: I,
+    price_level: I,
+}
+
+/// Adjusts the terms of any relation for inflation, yielding their nominal and real values side by side
+///
+/// Real values are given in the money of the first term yielded, so an already advanced relation
+/// is measured from its current number
+pub fn inflation_adjusted<R: IntoIterator<Item = I>, I: Num + Clone>(
+    relation: R,
+    inflation_rate: I,
+) -> InflationAdjusted<R::IntoIter, I> {
+    InflationAdjusted {
+        terms: relation.into_iter(),
+        inflation_factor: I::one() + inflation_rate,
+        price_level: I::one(),
+    }
+}
+
+impl<R: Iterator<Item = I>, I: Num + Clone> Iterator for InflationAdjusted<R, I> {
+    type Item = Adjusted<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let nominal = self.terms.next()?;
+        let real = nominal.clone() / self.price_level.clone();
+
+        self.price_level = self.price_level.clone() * self.inflation_factor.clone();
+
+        Some(Adjusted { nominal, real })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.terms.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::finance::compound_interest;
+
+    use super::*;
+
+    #[test]
+    fn test_inflation_adjusted() {
+        let balance = compound_interest(1000_f64, 0.05);
+
+        let mut adjusted = inflation_adjusted(balance, 0.05);
+
+        assert_eq!(
+            adjusted.next(),
+            Some(Adjusted {
+                nominal: 1000.,
+                real: 1000.
+            })
+        );
+
+        // Growth matching inflation keeps the real value constant
+        for term in adjusted.take(20) {
+            assert!((term.real - 1000.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_real_value_falls() {
+        let savings = crate::Relation::new(100., |x| x);
+
+        let last = inflation_adjusted(savings, 0.25).take(3).last().unwrap();
+
+        assert_eq!(
+            last,
+            Adjusted {
+                nominal: 100.,
+                real: 64.
+            }
+        );
+    }
+}
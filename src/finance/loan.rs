@@ -0,0 +1,286 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::{AffineRelation, Error};
+
+/// A loan repaid by a fixed repayment at the end of each period
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Loan<I> {
+    /// The amount borrowed
+    pub principal: I,
+    /// The interest rate per period
+    pub rate: I,
+    /// The repayment made at the end of each period
+    pub repayment: I,
+}
+
+/// One period of a loan's amortization schedule
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmortizationRow<I> {
+    /// The period, beginning at `1`
+    pub period: usize,
+    /// The balance owed at the start of the period
+    pub opening_balance: I,
+    /// The interest charged over the period
+    pub interest: I,
+    /// The repayment made at the end of the period
+    pub payment: I,
+    /// The amount the balance was reduced by, which is the payment less the interest
+    pub principal: I,
+    /// The balance owed at the end of the period
+    pub closing_balance: I,
+}
+
+/// How much sooner a loan is repaid with extra repayments or an offset account, compared to its baseline schedule
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Savings<I> {
+    /// The total interest which is no longer charged
+    pub interest_saved: I,
+    /// The number of periods the loan is repaid sooner by
+    pub periods_saved: usize,
+}
+
+impl<I: Num + Clone + PartialOrd> Loan<I> {
+    /// Create a new [`Loan`]
+    pub fn new(principal: I, rate: I, repayment: I) -> Self {
+        Self {
+            principal,
+            rate,
+            repayment,
+        }
+    }
+
+    /// Returns the relation for the balance owed, where `B(n+1) = B(n) * (1 + rate) - repayment`
+    ///
+    /// Unlike [`Loan::schedule`], the balance is not stopped at zero
+    pub fn balance(&self) -> AffineRelation<I> {
+        AffineRelation::new(
+            self.principal.clone(),
+            I::one() + self.rate.clone(),
+            I::zero() - self.repayment.clone(),
+        )
+    }
+
+    /// Calculates the next period of the schedule from the balance at the start of it,
+    /// with an extra repayment and the balance of an offset account for the period
+    ///
+    /// The final period's payment is reduced to exactly clear the balance
+    fn row(&self, period: usize, opening_balance: I, extra: I, offset: I) -> AmortizationRow<I> {
+        let charged = opening_balance.clone() - offset;
+        let charged = if charged > I::zero() {
+            charged
+        } else {
+            I::zero()
+        };
+
+        let interest = charged * self.rate.clone();
+        let owed = opening_balance.clone() + interest.clone();
+        let repayment = self.repayment.clone() + extra;
+
+        let payment = if owed < repayment {
+            owed.clone()
+        } else {
+            repayment
+        };
+
+        AmortizationRow {
+            period,
+            principal: payment.clone() - interest.clone(),
+            closing_balance: owed - payment.clone(),
+            opening_balance,
+            interest,
+            payment,
+        }
+    }
+
+    /// Returns an iterator over the amortization schedule, with one row per period until the loan is repaid
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest, as the loan would never be repaid
+    pub fn rows(&self) -> Result<impl Iterator<Item = AmortizationRow<I>> + '_, Error> {
+        self.rows_with(|_| I::zero(), |_| I::zero())
+    }
+
+    /// Returns an iterator over the amortization schedule, with `extra(period)` repaid on top of the repayment,
+    /// and interest charged only on the balance above `offset(period)`, the balance of an offset account
+    ///
+    /// Scheduled extra repayments and lump sums at specific periods are both given by `extra`.
+    /// Neither `extra` nor `offset` should be negative
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest without the extras or offset,
+    ///   as the loan may never be repaid
+    pub fn rows_with<'a>(
+        &'a self,
+        extra: impl Fn(usize) -> I + 'a,
+        offset: impl Fn(usize) -> I + 'a,
+    ) -> Result<impl Iterator<Item = AmortizationRow<I>> + 'a, Error> {
+        if self.repayment <= self.principal.clone() * self.rate.clone() {
+            return Err(Error::InsufficientRepayment);
+        }
+
+        let row = move |period: usize, opening_balance: I| {
+            self.row(period, opening_balance, extra(period), offset(period))
+        };
+
+        let first = (self.principal > I::zero()).then(|| row(1, self.principal.clone()));
+
+        Ok(core::iter::successors(first, move |previous| {
+            (previous.closing_balance > I::zero())
+                .then(|| row(previous.period + 1, previous.closing_balance.clone()))
+        }))
+    }
+
+    /// Compares the schedule from [`Loan::rows_with`] to the baseline schedule, reporting the interest and periods saved
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest, as the loan would never be repaid
+    pub fn savings(
+        &self,
+        extra: impl Fn(usize) -> I,
+        offset: impl Fn(usize) -> I,
+    ) -> Result<Savings<I>, Error> {
+        fn totals<I: Num + Clone>(rows: impl Iterator<Item = AmortizationRow<I>>) -> (I, usize) {
+            rows.fold((I::zero(), 0), |(interest, _), row| {
+                (interest + row.interest, row.period)
+            })
+        }
+
+        let (baseline_interest, baseline_periods) = totals(self.rows()?);
+        let (interest, periods) = totals(self.rows_with(extra, offset)?);
+
+        Ok(Savings {
+            interest_saved: baseline_interest - interest,
+            periods_saved: baseline_periods.saturating_sub(periods),
+        })
+    }
+
+    /// Returns the full amortization schedule, with one row per period until the loan is repaid
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest, as the loan would never be repaid
+    #[cfg(feature = "alloc")]
+    pub fn schedule(&self) -> Result<Vec<AmortizationRow<I>>, Error> {
+        Ok(self.rows()?.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_relation() {
+        let loan = Loan::new(1000., 0.01, 100.);
+
+        assert!(loan.balance().take(3).eq([1000., 910., 819.1]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_schedule() {
+        let loan = Loan::new(1000, 1, 1500);
+
+        let schedule = loan.schedule().unwrap();
+
+        assert_eq!(
+            schedule,
+            [
+                AmortizationRow {
+                    period: 1,
+                    opening_balance: 1000,
+                    interest: 1000,
+                    payment: 1500,
+                    principal: 500,
+                    closing_balance: 500,
+                },
+                AmortizationRow {
+                    period: 2,
+                    opening_balance: 500,
+                    interest: 500,
+                    payment: 1000,
+                    principal: 500,
+                    closing_balance: 0,
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_final_adjusted_payment() {
+        let loan = Loan::new(10_000., 0.005, 500.);
+
+        let schedule = loan.schedule().unwrap();
+        let last = schedule.last().unwrap();
+
+        assert_eq!(schedule.len(), 22);
+
+        assert!(last.payment < 500.);
+
+        assert_eq!(last.closing_balance, 0.);
+
+        let total_principal: f64 = schedule.iter().map(|row| row.principal).sum();
+
+        assert!((total_principal - 10_000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_extra_repayments() {
+        let loan = Loan::new(1000, 0, 100);
+
+        // A lump sum in the third period, on top of an extra 50 each period
+        let mut rows = loan
+            .rows_with(|period| if period == 3 { 250 } else { 50 }, |_| 0)
+            .unwrap();
+
+        assert_eq!(rows.nth(2).map(|row| row.payment), Some(350));
+
+        assert_eq!(rows.last().map(|row| row.period), Some(6));
+
+        let savings = loan
+            .savings(|period| if period == 3 { 250 } else { 50 }, |_| 0)
+            .unwrap();
+
+        assert_eq!(
+            savings,
+            Savings {
+                interest_saved: 0,
+                periods_saved: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        let loan = Loan::new(10_000., 0.01, 500.);
+
+        let mut rows = loan.rows_with(|_| 0., |_| 4000.).unwrap();
+
+        assert_eq!(rows.next().map(|row| row.interest), Some(60.));
+
+        // An offset larger than the balance means no interest is charged
+        assert!(loan
+            .rows_with(|_| 0., |_| 20_000.)
+            .unwrap()
+            .all(|row| row.interest == 0.));
+
+        let savings = loan.savings(|_| 0., |_| 4000.).unwrap();
+
+        assert!(savings.interest_saved > 0.);
+
+        assert!(savings.periods_saved > 0);
+    }
+
+    #[test]
+    fn test_insufficient_repayment() {
+        let loan = Loan::new(10_000., 0.05, 500.);
+
+        assert!(matches!(loan.rows(), Err(Error::InsufficientRepayment)));
+    }
+}
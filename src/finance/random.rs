@@ -0,0 +1,97 @@
+use num_traits::Float;
+use rand::Rng;
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::StochasticRelation;
+
+/// Create an additive random walk, where `S(n+1) = S(n) + drift * Δt + volatility * √Δt * Z`
+///
+/// `Z` is drawn from the standard normal distribution on each step, and `time_step` is `Δt`,
+/// so `drift` and `volatility` are given per unit of time
+pub fn random_walk<I: Float, R: Rng>(
+    start: I,
+    drift: I,
+    volatility: I,
+    time_step: I,
+    rng: R,
+) -> StochasticRelation<I, impl FnOnce(I, &mut R) -> I + Copy, R>
+where
+    StandardNormal: Distribution<I>,
+{
+    let change = drift * time_step;
+    let scale = volatility * time_step.sqrt();
+
+    StochasticRelation::new(start, rng, move |price: I, rng: &mut R| {
+        price + change + scale * StandardNormal.sample(rng)
+    })
+}
+
+/// Create a discretised geometric Brownian motion, where `S(n+1) = S(n) * exp((drift - volatility² / 2) * Δt + volatility * √Δt * Z)`
+///
+/// This is the standard model of a share price, which stays positive and has normally distributed log returns.
+/// `Z` is drawn from the standard normal distribution on each step, and `time_step` is `Δt`
+pub fn geometric_brownian_motion<I: Float, R: Rng>(
+    start: I,
+    drift: I,
+    volatility: I,
+    time_step: I,
+    rng: R,
+) -> StochasticRelation<I, impl FnOnce(I, &mut R) -> I + Copy, R>
+where
+    StandardNormal: Distribution<I>,
+{
+    let two = I::one() + I::one();
+    let change = (drift - volatility.powi(2) / two) * time_step;
+    let scale = volatility * time_step.sqrt();
+
+    StochasticRelation::new(start, rng, move |price: I, rng: &mut R| {
+        price * (change + scale * StandardNormal.sample(rng)).exp()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_random_walk() {
+        // Without volatility the walk is a straight line
+        let mut steady = random_walk(100., 2., 0., 0.5, StdRng::seed_from_u64(1));
+
+        assert_eq!(steady.nth(10), 110.);
+
+        let mut walk = random_walk(0., 0., 1., 1., StdRng::seed_from_u64(1));
+
+        let terms: [f64; 2] = [walk.nth(1), walk.nth(1)];
+
+        assert_ne!(terms[0], terms[1]);
+    }
+
+    #[test]
+    fn test_geometric_brownian_motion() {
+        let mut steady = geometric_brownian_motion(100., 0.05, 0., 1., StdRng::seed_from_u64(1));
+
+        assert!((steady.nth(10) - 100. * 0.5f64.exp()).abs() < 1e-9);
+
+        // The expected price after one unit of time is S * exp(drift)
+        let mut rng = StdRng::seed_from_u64(2);
+        let paths = 10_000;
+
+        let mean = (0..paths)
+            .map(|_| {
+                let seed = rng.random();
+                geometric_brownian_motion(100., 0.1, 0.2, 0.01, StdRng::seed_from_u64(seed))
+                    .nth(100)
+            })
+            .sum::<f64>()
+            / paths as f64;
+
+        assert!((mean - 100. * 0.1f64.exp()).abs() < 1.);
+
+        let always_positive = geometric_brownian_motion(1., -0.5, 2., 1., StdRng::seed_from_u64(3));
+
+        assert!(always_positive.take(50).all(|price| price > 0.));
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Num;
+
+use crate::LinearRecurrence;
+
+impl<I: Num + Clone> LinearRecurrence<I> {
+    /// Finds the shortest linear recurrence which generates `terms`, using the Berlekamp–Massey algorithm
+    ///
+    /// The recurrence begins at the first of `terms`. A recurrence of order `k` is only uniquely
+    /// determined by at least `2k` terms, so shorter samples may fit a different rule than intended.
+    /// `I` should support exact division, such as a rational or floating point type, rather than an integer type
+    ///
+    /// Returns [`None`] if `terms` is empty
+    pub fn fit(terms: &[I]) -> Option<Self> {
+        if terms.is_empty() {
+            return None;
+        }
+
+        // The connection polynomial, where terms[n] + c[1] terms[n-1] + ... + c[L] terms[n-L] = 0
+        let mut connection = vec![I::one()];
+        let mut previous = vec![I::one()];
+        let mut order = 0;
+        let mut shift = 1;
+        let mut previous_discrepancy = I::one();
+
+        for n in 0..terms.len() {
+            let discrepancy = (1..=order).fold(terms[n].clone(), |sum, i| {
+                sum + connection[i].clone() * terms[n - i].clone()
+            });
+
+            if discrepancy.is_zero() {
+                shift += 1;
+                continue;
+            }
+
+            let scale = discrepancy.clone() / previous_discrepancy.clone();
+            let replaced = connection.clone();
+
+            if connection.len() < previous.len() + shift {
+                connection.resize(previous.len() + shift, I::zero());
+            }
+            for (i, coefficient) in previous.iter().enumerate() {
+                connection[i + shift] =
+                    connection[i + shift].clone() - scale.clone() * coefficient.clone();
+            }
+
+            if 2 * order <= n {
+                order = n + 1 - order;
+                previous = replaced;
+                previous_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+
+        if order == 0 {
+            // Every term is zero
+            return Some(Self::new([I::zero()], [I::zero()]));
+        }
+
+        connection.resize(order + 1, I::zero());
+        let coefficients: Vec<I> = connection
+            .into_iter()
+            .skip(1)
+            .map(|c| I::zero() - c)
+            .collect();
+
+        Some(Self::new(coefficients, &terms[..order]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_fibonacci() {
+        let terms = [0., 1., 1., 2., 3., 5., 8., 13., 21., 34.];
+
+        let recurrence = LinearRecurrence::fit(&terms).unwrap();
+
+        assert_eq!(recurrence.coefficients(), [1., 1.]);
+
+        assert_eq!(recurrence.terms(), [0., 1.]);
+
+        assert!(recurrence.take(10).eq(terms));
+    }
+
+    #[test]
+    fn test_fit_higher_order() {
+        let tribonacci = LinearRecurrence::new([1., 1., 1.], [0., 0., 1.]);
+        let terms: Vec<f64> = tribonacci.clone().take(12).collect();
+
+        assert_eq!(LinearRecurrence::fit(&terms), Some(tribonacci));
+
+        let geometric = [3., 6., 12., 24.];
+
+        assert_eq!(
+            LinearRecurrence::fit(&geometric).unwrap().coefficients(),
+            [2.]
+        );
+    }
+
+    #[test]
+    fn test_fit_degenerate() {
+        assert_eq!(LinearRecurrence::<f64>::fit(&[]), None);
+
+        let zeros = LinearRecurrence::fit(&[0., 0., 0.]).unwrap();
+
+        assert!(zeros.take(5).eq([0.; 5]));
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn test_fit_rational() {
+        use num_rational::Ratio;
+
+        // t(n+2) = t(n+1) / 2 + t(n) / 3
+        let recurrence = LinearRecurrence::new(
+            [Ratio::new(1i64, 2), Ratio::new(1, 3)],
+            [Ratio::from(1), Ratio::from(2)],
+        );
+        let terms: Vec<_> = recurrence.clone().take(8).collect();
+
+        assert_eq!(LinearRecurrence::fit(&terms), Some(recurrence));
+    }
+}
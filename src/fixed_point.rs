@@ -0,0 +1,246 @@
+use num_traits::Float;
+
+use crate::{Error, Relation};
+
+/// How nearby terms behave around a fixed point
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Stability {
+    /// Nearby terms move towards the fixed point, as `|f'(x)| < 1`
+    Attracting,
+    /// Nearby terms move away from the fixed point, as `|f'(x)| > 1`
+    Repelling,
+    /// The derivative is too close to `1` in magnitude to tell, as `|f'(x)| ≈ 1`
+    Neutral,
+}
+
+impl Stability {
+    /// Classifies a fixed point from the derivative of the relation at that point
+    ///
+    /// Derivatives within `tolerance` of `1` in magnitude are considered [`Stability::Neutral`]
+    pub fn from_derivative<I: Float>(derivative: I, tolerance: I) -> Self {
+        let magnitude = derivative.abs();
+
+        if (magnitude - I::one()).abs() <= tolerance {
+            Stability::Neutral
+        } else if magnitude < I::one() {
+            Stability::Attracting
+        } else {
+            Stability::Repelling
+        }
+    }
+}
+
+/// A fixed point of a relation, where `f(x) ≈ x`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FixedPoint<I> {
+    /// The value of the fixed point
+    pub value: I,
+    /// The numerically estimated derivative of the relation at the fixed point
+    pub derivative: I,
+    /// Whether the fixed point is attracting or repelling
+    pub stability: Stability,
+}
+
+/// The range of starters around a fixed point which were found to converge to it
+///
+/// Found by [`Relation::basin`]. The bounds are the furthest sampled starters on each side,
+/// so the true boundary lies between them and the next sample out
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Basin<I> {
+    /// The smallest starter found to converge, below the fixed point
+    pub lower: I,
+    /// The largest starter found to converge, above the fixed point
+    pub upper: I,
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Classifies a candidate fixed point as attracting, repelling, or neutral, from the derivative of the relation there
+    ///
+    /// Derivatives within `tolerance` of `1` in magnitude are considered [`Stability::Neutral`].
+    /// The point is not checked to be a fixed point, which [`Relation::fixed_point`] can find
+    pub fn stability_at(&self, point: I, tolerance: I) -> Stability {
+        Stability::from_derivative(self.derivative_at(point), tolerance)
+    }
+
+    /// Estimates the basin of attraction of a fixed point, by sampling starters `spacing` apart on either side of it
+    ///
+    /// A starter converges if it is within `tolerance` of `point` after `steps` steps.
+    /// Sampling stops on each side at the first starter which does not converge, or after `max_samples` starters
+    pub fn basin(
+        &self,
+        point: I,
+        spacing: I,
+        max_samples: usize,
+        steps: usize,
+        tolerance: I,
+    ) -> Basin<I> {
+        let converges = |starter: I| {
+            let end = (0..steps).fold(starter, |x, _| (self.relation)(x));
+
+            (end - point).abs() < tolerance
+        };
+
+        let furthest = |direction: I| {
+            let mut furthest = point;
+
+            for sample in 1..=max_samples {
+                let starter = point + direction * spacing * I::from(sample).unwrap_or_else(I::nan);
+
+                if !converges(starter) {
+                    break;
+                }
+
+                furthest = starter;
+            }
+
+            furthest
+        };
+
+        Basin {
+            lower: furthest(-I::one()),
+            upper: furthest(I::one()),
+        }
+    }
+
+    /// Estimates the derivative of the relation at `x` using a central difference
+    pub fn derivative_at(&self, x: I) -> I {
+        let step = I::epsilon().sqrt() * x.abs().max(I::one());
+
+        ((self.relation)(x + step) - (self.relation)(x - step)) / (step + step)
+    }
+
+    /// Finds a fixed point of the relation, starting the search from the current number
+    ///
+    /// Uses the secant method on `f(x) - x`, so unlike plain iteration it can also find repelling fixed points.
+    /// The relation itself is not advanced
+    ///
+    /// The stability is classified with a tolerance of `sqrt(epsilon)` around a derivative of magnitude `1`,
+    /// the precision of the estimated derivative, rather than with the `tolerance` of the residual
+    ///
+    /// # Errors
+    /// - If no point with `|f(x) - x| < tolerance` is found within `max_steps` steps,
+    ///   or the search stalls before then, with the number of steps actually taken
+    pub fn fixed_point(&self, tolerance: I, max_steps: usize) -> Result<FixedPoint<I>, Error> {
+        let residual = |x: I| (self.relation)(x) - x;
+
+        let mut previous = self.current_number;
+        let mut current = self.calculate_next();
+
+        for steps in 0..max_steps {
+            let current_residual = residual(current);
+
+            if current_residual.abs() < tolerance {
+                let derivative = self.derivative_at(current);
+
+                return Ok(FixedPoint {
+                    value: current,
+                    derivative,
+                    stability: Stability::from_derivative(derivative, I::epsilon().sqrt()),
+                });
+            }
+
+            let slope = current_residual - residual(previous);
+
+            if slope.is_zero() || !slope.is_finite() {
+                return Err(Error::DidNotConverge { steps });
+            }
+
+            let next = current - current_residual * (current - previous) / slope;
+
+            previous = current;
+            current = next;
+        }
+
+        Err(Error::DidNotConverge { steps: max_steps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attracting_fixed_point() {
+        let relation = Relation::new(0., |x: f64| x.cos());
+
+        let fixed_point = relation.fixed_point(1e-12, 100).unwrap();
+
+        assert!((fixed_point.value - 0.739_085_133_215_160_6).abs() < 1e-10);
+
+        assert!((fixed_point.derivative + fixed_point.value.sin()).abs() < 1e-6);
+
+        assert_eq!(fixed_point.stability, Stability::Attracting);
+
+        assert_eq!(relation.current_number, 0.);
+    }
+
+    #[test]
+    fn test_repelling_fixed_point() {
+        let relation = Relation::new(0., |x: f64| 3. * x - 2.);
+
+        let fixed_point = relation.fixed_point(1e-12, 100).unwrap();
+
+        assert!((fixed_point.value - 1.).abs() < 1e-10);
+
+        assert!((fixed_point.derivative - 3.).abs() < 1e-6);
+
+        assert_eq!(fixed_point.stability, Stability::Repelling);
+    }
+
+    #[test]
+    fn test_stability_independent_of_tolerance() {
+        let relation = Relation::new(0., |x: f64| 0.95 * x + 0.05);
+
+        let fixed_point = relation.fixed_point(0.1, 100).unwrap();
+
+        assert_eq!(fixed_point.stability, Stability::Attracting);
+    }
+
+    #[test]
+    fn test_stability_at() {
+        let logistic = Relation::new(0.1, |x: f64| 2.5 * x * (1. - x));
+
+        assert_eq!(logistic.stability_at(0.6, 1e-6), Stability::Attracting);
+
+        assert_eq!(logistic.stability_at(0., 1e-6), Stability::Repelling);
+
+        let identity = Relation::new(0., |x: f64| x);
+
+        assert_eq!(identity.stability_at(3., 1e-6), Stability::Neutral);
+    }
+
+    #[test]
+    fn test_basin() {
+        // Every starter strictly between 0 and 1 converges to the fixed point at 0.6
+        let logistic = Relation::new(0.1, |x: f64| 2.5 * x * (1. - x));
+
+        let basin = logistic.basin(0.6, 0.05, 100, 1000, 1e-9);
+
+        assert!((basin.lower - 0.05).abs() < 1e-9);
+
+        assert!((basin.upper - 0.95).abs() < 1e-9);
+
+        let limited = logistic.basin(0.6, 0.05, 2, 1000, 1e-9);
+
+        assert!((limited.upper - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_fixed_point() {
+        // The residual is always 1, so the search stalls on its first step
+        let relation = Relation::new(0., |x: f64| x + 1.);
+
+        assert_eq!(
+            relation.fixed_point(1e-12, 100),
+            Err(Error::DidNotConverge { steps: 0 })
+        );
+
+        // The residual shrinks towards 0 forever without reaching the tolerance
+        let relation = Relation::new(1., |x: f64| x + (-x).exp());
+
+        assert_eq!(
+            relation.fixed_point(1e-300, 5),
+            Err(Error::DidNotConverge { steps: 5 })
+        );
+    }
+}
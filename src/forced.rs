@@ -0,0 +1,395 @@
+use alloc::vec::Vec;
+
+use num_traits::{Float, FromPrimitive, Num};
+
+use crate::{closed_form::power, Error, LinearRecurrence};
+
+/// The forcing term `g(n)` added to each step of a [`ForcedRecurrence`], where `n` is the index of the newest term
+///
+/// Every forcing term but [`Forcing::Function`] can be serialized
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Forcing<I> {
+    /// `g(n) = c`, such as a regular contribution
+    Constant(I),
+    /// `g(n) = c0 + c1 * n + c2 * n^2 + ...`, with the coefficients ordered from `c0`
+    Polynomial(Vec<I>),
+    /// `g(n) = scale * ratio^n`, such as a contribution which grows each period
+    Geometric {
+        /// The forcing term at index `0`
+        scale: I,
+        /// The rate the forcing term grows by each step
+        ratio: I,
+    },
+    /// Any other forcing term, which has no closed form
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Function(fn(usize) -> I),
+}
+
+impl<I: Num + Clone + FromPrimitive> Forcing<I> {
+    /// Evaluates the forcing term at index `n`
+    ///
+    /// # Panics
+    /// - If the forcing term is a polynomial, and `n` cannot be represented by the number type
+    pub fn evaluate(&self, n: usize) -> I {
+        match self {
+            Forcing::Constant(constant) => constant.clone(),
+            Forcing::Polynomial(coefficients) => {
+                let n = I::from_usize(n).expect("the index should fit in the number type");
+
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(I::zero(), |sum, coefficient| {
+                        sum * n.clone() + coefficient.clone()
+                    })
+            }
+            Forcing::Geometric { scale, ratio } => {
+                scale.clone() * num_traits::pow(ratio.clone(), n)
+            }
+            Forcing::Function(function) => function(n),
+        }
+    }
+}
+
+/// A linear recurrence with a forcing term, where `t(n+k) = c1 * t(n+k-1) + ... + ck * t(n) + g(n+k-1)`
+///
+/// Created by [`LinearRecurrence::with_forcing`]. For order one this is `t(n+1) = a * t(n) + g(n)`,
+/// which models balances with contributions, and most textbook recurrences
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForcedRecurrence<I> {
+    recurrence: LinearRecurrence<I>,
+    forcing: Forcing<I>,
+}
+
+impl<I: Num + Clone> LinearRecurrence<I> {
+    /// Adds a forcing term to each step of the recurrence, keeping the current terms and index
+    pub fn with_forcing(self, forcing: Forcing<I>) -> ForcedRecurrence<I> {
+        ForcedRecurrence {
+            recurrence: self,
+            forcing,
+        }
+    }
+}
+
+impl<I: Num + Clone + FromPrimitive> ForcedRecurrence<I> {
+    /// Returns the coefficients, ordered from `c1` to `ck`
+    pub fn coefficients(&self) -> &[I] {
+        self.recurrence.coefficients()
+    }
+
+    /// Returns the forcing term
+    pub fn forcing(&self) -> &Forcing<I> {
+        &self.forcing
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        self.recurrence.current()
+    }
+
+    /// Returns the current number, followed by the `k - 1` numbers after it
+    pub fn terms(&self) -> &[I] {
+        self.recurrence.terms()
+    }
+
+    /// Returns the index of the current number, where the first initial term is at index `0`
+    pub fn index(&self) -> usize {
+        self.recurrence.index()
+    }
+
+    /// Calculates the number after the last of [`ForcedRecurrence::terms`]
+    fn calculate_following(&self) -> I {
+        let newest = self.index() + self.recurrence.order() - 1;
+
+        self.recurrence.calculate_following() + self.forcing.evaluate(newest)
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        match self.terms().get(1) {
+            Some(next) => next.clone(),
+            None => self.calculate_following(),
+        }
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        let following = self.calculate_following();
+
+        self.recurrence.advance_to(following);
+    }
+
+    /// Calls [`ForcedRecurrence::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current().clone()
+    }
+}
+
+/// The particular solution of a [`ForcedClosedForm`], which accounts for the forcing term
+#[derive(Debug, Clone, PartialEq)]
+pub enum Particular<I> {
+    /// `p(n) = p0 + p1 * n + p2 * n^2 + ...`, for polynomial and constant forcing terms
+    Polynomial(Vec<I>),
+    /// `p(n) = scale * ratio^n`
+    Geometric {
+        /// The coefficient of the ratio
+        scale: I,
+        /// The ratio of the forcing term
+        ratio: I,
+    },
+    /// `p(n) = scale * n * ratio^(n-1)`, when the forcing term grows at the same rate as the recurrence
+    Resonant {
+        /// The forcing term at index `0`
+        scale: I,
+        /// The ratio shared by the forcing term and the recurrence
+        ratio: I,
+    },
+}
+
+/// The closed form of a forced recurrence of order one, `t(n) = coefficient * a^n + p(n)`
+///
+/// Found by [`ForcedRecurrence::closed_form`]. Like [`ClosedForm`](crate::ClosedForm),
+/// it gives the term `n` steps after the current number of the recurrence it was solved from
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForcedClosedForm<I> {
+    /// The multiplier `a` of the recurrence
+    pub multiplier: I,
+    /// The coefficient of `a^n`, from the current number
+    pub coefficient: I,
+    /// The particular solution `p`, in terms of the index of each term rather than the steps after the current number
+    pub particular: Particular<I>,
+    /// The index of the current number the closed form was solved from
+    pub start: usize,
+}
+
+impl<I: Float> Particular<I> {
+    /// Evaluates the particular solution at index `n`
+    fn evaluate(&self, n: usize) -> I {
+        let n_float = I::from(n).unwrap_or_else(I::infinity);
+
+        match *self {
+            Particular::Polynomial(ref coefficients) => coefficients
+                .iter()
+                .rev()
+                .fold(I::zero(), |sum, &coefficient| sum * n_float + coefficient),
+            Particular::Geometric { scale, ratio } => scale * power(ratio, n),
+            Particular::Resonant { scale, ratio } => match n {
+                0 => I::zero(),
+                _ => scale * n_float * power(ratio, n - 1),
+            },
+        }
+    }
+
+    /// Solves for the polynomial `p` where `p(n+1) = a * p(n) + g(n)`, by matching the coefficients of each power of `n`
+    fn polynomial(multiplier: I, forcing: &[I]) -> Self {
+        // The binomial coefficient C(j, i), as the coefficient of n^i in (n + 1)^j
+        let binomial = |j: usize, i: usize| {
+            (0..i).fold(I::one(), |product, k| {
+                product * I::from(j - k).unwrap_or_else(I::nan)
+                    / I::from(k + 1).unwrap_or_else(I::nan)
+            })
+        };
+
+        // With a = 1 the particular solution is a degree higher, such as a constant forcing term summing to c * n
+        let shift = usize::from(multiplier == I::one());
+        let mut solution = alloc::vec![I::zero(); forcing.len() + shift];
+
+        for i in (0..forcing.len()).rev() {
+            let known = (i + shift + 1..solution.len())
+                .fold(I::zero(), |sum, j| sum + binomial(j, i) * solution[j]);
+
+            solution[i + shift] = (forcing[i] - known)
+                / match shift {
+                    0 => I::one() - multiplier,
+                    _ => I::from(i + 1).unwrap_or_else(I::nan),
+                };
+        }
+
+        Particular::Polynomial(solution)
+    }
+}
+
+impl<I: Float> ForcedClosedForm<I> {
+    /// Evaluates the closed form for the term `n` steps after the current number
+    pub fn evaluate(&self, n: usize) -> I {
+        self.coefficient * power(self.multiplier, n) + self.particular.evaluate(self.start + n)
+    }
+}
+
+impl<I: Float + FromPrimitive> ForcedRecurrence<I> {
+    /// Solves the recurrence for its closed form, from the homogeneous solution and a particular solution for the forcing term
+    ///
+    /// The closed form is relative to the current number, so `closed_form.evaluate(n)` matches the term `n` steps later
+    ///
+    /// # Errors
+    /// - If the order is greater than one
+    /// - If the forcing term is a [`Forcing::Function`], which is not recognised
+    pub fn closed_form(&self) -> Result<ForcedClosedForm<I>, Error> {
+        let (&[multiplier], &[current]) = (self.coefficients(), self.terms()) else {
+            return Err(Error::NoClosedForm);
+        };
+
+        let particular = match self.forcing {
+            Forcing::Constant(constant) => Particular::polynomial(multiplier, &[constant]),
+            Forcing::Polynomial(ref coefficients) => {
+                Particular::polynomial(multiplier, coefficients)
+            }
+            Forcing::Geometric { scale, ratio } if ratio == multiplier => {
+                Particular::Resonant { scale, ratio }
+            }
+            Forcing::Geometric { scale, ratio } => Particular::Geometric {
+                scale: scale / (ratio - multiplier),
+                ratio,
+            },
+            Forcing::Function(_) => return Err(Error::NoClosedForm),
+        };
+
+        // The homogeneous part makes up the difference between the particular solution and the current number
+        let offset = current - particular.evaluate(self.index());
+
+        Ok(ForcedClosedForm {
+            multiplier,
+            coefficient: offset,
+            particular,
+            start: self.index(),
+        })
+    }
+}
+
+impl<I: Num + Clone + FromPrimitive> Iterator for ForcedRecurrence<I> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current().clone();
+        ForcedRecurrence::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::closed_form::tests::assert_closed_form_matches;
+
+    fn assert_matches_iteration(recurrence: ForcedRecurrence<f64>) {
+        let closed_form = recurrence.closed_form().unwrap();
+
+        assert_closed_form_matches(
+            &closed_form,
+            |n| closed_form.evaluate(n),
+            recurrence.take(30),
+        );
+    }
+
+    #[test]
+    fn test_forced_recurrence() {
+        // Savings earning 10%, with 100 added each period
+        let mut savings = LinearRecurrence::new([1.1], [0.]).with_forcing(Forcing::Constant(100.));
+
+        assert_eq!(savings.calculate_next(), 100.);
+
+        assert!((savings.nth(2) - 210.).abs() < 1e-9);
+
+        // t(n+1) = t(n) + n, the triangular numbers
+        let triangular =
+            LinearRecurrence::new([1u64], [0]).with_forcing(Forcing::Polynomial(alloc::vec![0, 1]));
+
+        assert!(triangular.take(5).eq([0, 0, 1, 3, 6]));
+
+        let doubling = LinearRecurrence::new([1u64], [1])
+            .with_forcing(Forcing::Geometric { scale: 1, ratio: 2 });
+
+        assert!(doubling.take(5).eq([1, 2, 4, 8, 16]));
+
+        let fibonacci_plus_one =
+            LinearRecurrence::new([1u64, 1], [0, 1]).with_forcing(Forcing::Function(|_| 1));
+
+        assert!(fibonacci_plus_one.take(6).eq([0, 1, 2, 4, 7, 12]));
+    }
+
+    #[test]
+    fn test_closed_forms() {
+        let first_order =
+            |a: f64, t0: f64, forcing| LinearRecurrence::new([a], [t0]).with_forcing(forcing);
+
+        assert_matches_iteration(first_order(1.1, 50., Forcing::Constant(100.)));
+
+        assert_matches_iteration(first_order(1., 50., Forcing::Constant(100.)));
+
+        let quadratic = Forcing::Polynomial(alloc::vec![1., -2., 0.5]);
+
+        assert_matches_iteration(first_order(0.9, 3., quadratic.clone()));
+
+        assert_matches_iteration(first_order(1., 3., quadratic));
+
+        let growing = Forcing::Geometric {
+            scale: 10.,
+            ratio: 1.03,
+        };
+
+        assert_matches_iteration(first_order(1.07, 0., growing));
+
+        let resonant = Forcing::Geometric {
+            scale: 10.,
+            ratio: 1.07,
+        };
+
+        assert_matches_iteration(first_order(1.07, 5., resonant));
+    }
+
+    #[test]
+    fn test_closed_form_from_later_index() {
+        let mut recurrence = LinearRecurrence::new([0.5], [8.])
+            .with_forcing(Forcing::Polynomial(alloc::vec![1., 1.]));
+        recurrence.nth(7);
+
+        assert_matches_iteration(recurrence);
+    }
+
+    #[test]
+    fn test_no_closed_form() {
+        let custom =
+            LinearRecurrence::new([2.], [1.]).with_forcing(Forcing::Function(|n| n as f64));
+
+        assert_eq!(custom.closed_form(), Err(Error::NoClosedForm));
+
+        let second_order =
+            LinearRecurrence::new([1., 1.], [0., 1.]).with_forcing(Forcing::Constant(1.));
+
+        assert_eq!(second_order.closed_form(), Err(Error::NoClosedForm));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_forced_recurrence_round_trip() {
+        let mut savings = LinearRecurrence::new([1.5], [0.]).with_forcing(Forcing::Constant(100.));
+        savings.nth(2);
+
+        let json = serde_json::to_string(&savings).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"recurrence":{"coefficients":[1.5],"terms":[250.0],"index":2},"forcing":{"Constant":100.0}}"#
+        );
+
+        let resumed: ForcedRecurrence<f64> = serde_json::from_str(&json).unwrap();
+
+        assert!(resumed.take(3).eq(savings.take(3)));
+
+        let custom =
+            LinearRecurrence::new([1.], [0.]).with_forcing(Forcing::Function(|n| n as f64));
+
+        assert!(serde_json::to_string(&custom).is_err());
+    }
+}
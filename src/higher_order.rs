@@ -0,0 +1,120 @@
+use num_traits::Num;
+
+/// A relation where each term depends on the last `K` terms
+///
+/// Takes a relation function and the `K` starter numbers, ordered from oldest to newest.
+/// The relation function is given the same window, and returns the next number in the sequence
+///
+/// This covers recurrences such as the Fibonacci sequence, where `t(n+1) = t(n) + t(n-1)`
+#[derive(Debug, Copy, Clone)]
+pub struct HigherOrderRelation<I, F, const K: usize> {
+    terms: [I; K],
+    index: usize,
+    relation: F,
+}
+
+impl<I: Num + Clone, F: FnOnce([I; K]) -> I + Copy, const K: usize> HigherOrderRelation<I, F, K> {
+    /// Create a new [`HigherOrderRelation`]
+    ///
+    /// # Panics
+    /// - If `K` is zero, as there would be no current number
+    pub fn new(starters: [I; K], relation: F) -> Self {
+        assert!(K > 0, "a higher order relation requires at least one term");
+
+        Self {
+            terms: starters,
+            index: K - 1,
+            relation,
+        }
+    }
+
+    /// Returns the newest term
+    pub fn current(&self) -> &I {
+        &self.terms[K - 1]
+    }
+
+    /// Returns the index of the newest term, where the oldest starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the window of terms, ordered from oldest to newest
+    pub fn terms(&self) -> &[I; K] {
+        &self.terms
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        (self.relation)(self.terms.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    ///
+    /// The oldest term is dropped from the window
+    pub fn next(&mut self) {
+        let next = self.calculate_next();
+
+        self.terms.rotate_left(1);
+        self.terms[K - 1] = next;
+        self.index += 1;
+    }
+
+    /// Calls [`HigherOrderRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.terms[K - 1].clone()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce([I; K]) -> I + Copy, const K: usize> Iterator
+    for HigherOrderRelation<I, F, K>
+{
+    type Item = I;
+
+    /// Yields the newest term and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.terms[K - 1].clone();
+        HigherOrderRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci() {
+        let mut relation = HigherOrderRelation::new([0, 1], |[a, b]| a + b);
+
+        assert_eq!(relation.calculate_next(), 1);
+
+        relation.next();
+
+        assert_eq!(relation.terms, [1, 1]);
+
+        assert_eq!(relation.nth(8), 55);
+
+        assert_eq!(relation.index(), 10);
+    }
+
+    #[test]
+    fn test_tribonacci_iterator() {
+        let relation = HigherOrderRelation::new([0, 0, 1], |[a, b, c]| a + b + c);
+
+        assert!(relation.take(8).eq([1, 1, 2, 4, 7, 13, 24, 44]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_window() {
+        HigherOrderRelation::new([0; 0], |_: [i32; 0]| 0);
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Float;
+
+use crate::Relation;
+
+/// The counts of terms falling into equal width bins
+///
+/// Created by [`Relation::histogram`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Histogram<I> {
+    low: I,
+    high: I,
+    counts: Vec<usize>,
+    outside: usize,
+}
+
+impl<I: Float> Histogram<I> {
+    /// Create an empty [`Histogram`] of `bins` equal width bins covering `low` to `high`
+    pub(crate) fn new(low: I, high: I, bins: usize) -> Self {
+        Self {
+            low,
+            high,
+            counts: vec![0; bins],
+            outside: 0,
+        }
+    }
+
+    /// Returns the lower edge of the first bin
+    pub fn low(&self) -> I {
+        self.low
+    }
+
+    /// Returns the upper edge of the last bin
+    pub fn high(&self) -> I {
+        self.high
+    }
+
+    /// Returns the number of terms in each bin
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Returns the number of terms which fell outside every bin, including `NaN` terms
+    pub fn outside(&self) -> usize {
+        self.outside
+    }
+
+    /// Returns the width of each bin
+    pub fn bin_width(&self) -> I {
+        (self.high - self.low) / I::from(self.counts.len()).unwrap_or_else(I::nan)
+    }
+
+    /// Returns the lower and upper edges of the bin at `bin`
+    pub fn bin_edges(&self, bin: usize) -> (I, I) {
+        let width = self.bin_width();
+        let lower = self.low + width * I::from(bin).unwrap_or_else(I::nan);
+
+        (lower, lower + width)
+    }
+
+    /// Adds a term to the bin containing it, where the last bin also includes its upper edge
+    pub(crate) fn add(&mut self, term: I) {
+        if !(term >= self.low && term <= self.high) || self.counts.is_empty() {
+            self.outside += 1;
+            return;
+        }
+
+        let bins = self.counts.len();
+        let position = ((term - self.low) / self.bin_width())
+            .to_usize()
+            .unwrap_or(0);
+
+        self.counts[position.min(bins - 1)] += 1;
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Counts `count` terms, beginning with the current number, into `bins` equal width bins, without updating the struct
+    ///
+    /// The bins cover `range` if it is given, or otherwise the smallest to largest of the terms,
+    /// which calculates the terms twice rather than storing them
+    pub fn histogram(&self, count: usize, bins: usize, range: Option<(I, I)>) -> Histogram<I> {
+        let (low, high) = range
+            .or_else(|| self.stats(count).map(|stats| (stats.min(), stats.max())))
+            .unwrap_or((I::zero(), I::zero()));
+
+        let mut histogram = Histogram::new(low, high, bins);
+
+        let mut term = self.current_number;
+
+        for position in 0..count {
+            if position > 0 {
+                term = (self.relation)(term);
+            }
+
+            histogram.add(term);
+        }
+
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        let relation = Relation::new(0., |x| x + 1.);
+
+        let histogram = relation.histogram(10, 5, Some((0., 10.)));
+
+        assert_eq!(histogram.counts(), [2, 2, 2, 2, 2]);
+
+        assert_eq!(histogram.bin_edges(1), (2., 4.));
+
+        let narrow = relation.histogram(10, 2, Some((2., 5.)));
+
+        assert_eq!(narrow.counts(), [2, 2]);
+
+        assert_eq!(narrow.outside(), 6);
+
+        // Without a range, the bins span the terms, so the largest term lands in the last bin
+        let spanning = relation.histogram(10, 3, None);
+
+        assert_eq!((spanning.low(), spanning.high()), (0., 9.));
+
+        assert_eq!(spanning.counts(), [3, 3, 4]);
+    }
+
+    #[test]
+    fn test_logistic_map_distribution() {
+        let logistic = Relation::new(0.1, |x: f64| 4. * x * (1. - x));
+
+        let histogram = logistic.histogram(100_000, 10, Some((0., 1.)));
+
+        // The invariant density of the chaotic logistic map peaks at the edges
+        let counts = histogram.counts();
+
+        assert!(counts[0] > counts[5] * 2);
+
+        assert!(counts[9] > counts[4] * 2);
+
+        assert_eq!(counts.iter().sum::<usize>(), 100_000);
+    }
+}
@@ -0,0 +1,102 @@
+use num_traits::Num;
+
+/// A relation where each term also depends on its own index
+///
+/// Takes a relation function and a starter number, which is the term at index `0`.
+/// The relation function is given the index `n` and the current term, and returns the term at index `n + 1`
+///
+/// This covers recurrences such as `t(n+1) = t(n) + n^2`
+#[derive(Debug, Copy, Clone)]
+pub struct IndexedRelation<I, F> {
+    index: usize,
+    current_number: I,
+    relation: F,
+}
+
+impl<I: Num + Clone, F: FnOnce(usize, I) -> I + Copy> IndexedRelation<I, F> {
+    /// Create a new [`IndexedRelation`]
+    pub fn new(starter: I, relation: F) -> Self {
+        Self {
+            index: 0,
+            current_number: starter,
+            relation,
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        (self.relation)(self.index, self.current_number.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current_number = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`IndexedRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_number.clone()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(usize, I) -> I + Copy> Iterator for IndexedRelation<I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        IndexedRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexed_relation() {
+        let mut relation = IndexedRelation::new(0, |n, x| x + (n * n) as i64);
+
+        assert_eq!(relation.index(), 0);
+
+        assert_eq!(relation.calculate_next(), 0);
+
+        relation.next();
+
+        assert_eq!(relation.index(), 1);
+
+        assert_eq!(relation.current_number, 0);
+
+        // Sum of the squares of 0..10
+        assert_eq!(relation.nth(9), 285);
+
+        assert_eq!(relation.index(), 10);
+    }
+
+    #[test]
+    fn test_indexed_iterator() {
+        let relation = IndexedRelation::new(1, |n, x| x * (n as i64 + 1));
+
+        assert!(relation.take(6).eq([1, 1, 2, 6, 24, 120]));
+    }
+}
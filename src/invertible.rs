@@ -0,0 +1,195 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// A relation with an inverse relation function, so it can be stepped backwards as well as forwards
+///
+/// Created by [`Relation::with_inverse`]. Stepping back past the starter number is allowed,
+/// so the index is signed, and becomes negative for terms before the starter.
+/// Its iterator runs forwards, and [`InvertibleRelation::backwards`] turns it into one which runs backwards
+#[derive(Debug, Copy, Clone)]
+pub struct InvertibleRelation<I, F, G> {
+    current_number: I,
+    index: isize,
+    relation: F,
+    inverse: G,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Adds the inverse of the relation function, so the relation can be stepped backwards
+    ///
+    /// `inverse` must undo the relation function, so `inverse(relation(x)) == x`,
+    /// such as discounting for a relation which compounds
+    pub fn with_inverse<G: FnOnce(I) -> I + Copy>(self, inverse: G) -> InvertibleRelation<I, F, G> {
+        InvertibleRelation {
+            current_number: self.current_number,
+            index: self.index as isize,
+            relation: self.relation,
+            inverse,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, G: FnOnce(I) -> I + Copy>
+    InvertibleRelation<I, F, G>
+{
+    /// Create a new [`InvertibleRelation`]
+    pub fn new(starter: I, relation: F, inverse: G) -> Self {
+        Self {
+            current_number: starter,
+            index: 0,
+            relation,
+            inverse,
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> isize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        (self.relation)(self.current_number.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current_number = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`InvertibleRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_number.clone()
+    }
+
+    /// Calculates and returns the previous number in the sequence, using the inverse relation function
+    pub fn prev_calculated(&self) -> I {
+        (self.inverse)(self.current_number.clone())
+    }
+
+    /// Calculates the previous number in the sequence and updates the struct
+    pub fn prev(&mut self) {
+        self.current_number = self.prev_calculated();
+        self.index -= 1;
+    }
+
+    /// Calls [`InvertibleRelation::prev`] `steps` times, and then returns the result
+    pub fn back(&mut self, steps: usize) -> I {
+        for _ in 0..steps {
+            self.prev();
+        }
+
+        self.current_number.clone()
+    }
+
+    /// Turns the relation into an iterator over the terms before the current number, newest first
+    pub fn backwards(self) -> Backwards<I, F, G> {
+        Backwards { relation: self }
+    }
+}
+
+/// An iterator which steps an [`InvertibleRelation`] back, yielding each earlier term in turn
+///
+/// Created by [`InvertibleRelation::backwards`]
+#[derive(Debug, Copy, Clone)]
+pub struct Backwards<I, F, G> {
+    relation: InvertibleRelation<I, F, G>,
+}
+
+impl<I, F, G> Backwards<I, F, G> {
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &InvertibleRelation<I, F, G> {
+        &self.relation
+    }
+
+    /// Returns the underlying relation, at the last term yielded
+    pub fn into_inner(self) -> InvertibleRelation<I, F, G> {
+        self.relation
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, G: FnOnce(I) -> I + Copy> Iterator
+    for Backwards<I, F, G>
+{
+    type Item = I;
+
+    /// Steps the relation back and then yields the new current number
+    fn next(&mut self) -> Option<Self::Item> {
+        self.relation.prev();
+        Some(self.relation.current_number.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, G: FnOnce(I) -> I + Copy> Iterator
+    for InvertibleRelation<I, F, G>
+{
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        InvertibleRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stepping_back() {
+        let mut relation = Relation::new(3, |x| x * 2).with_inverse(|x| x / 2);
+
+        assert_eq!(relation.nth(3), 24);
+
+        assert_eq!(relation.prev_calculated(), 12);
+
+        assert_eq!(relation.back(2), 6);
+
+        assert_eq!(relation.index(), 1);
+
+        relation.prev();
+
+        relation.prev();
+
+        assert_eq!(relation.index(), -1);
+    }
+
+    #[test]
+    fn test_discounting_backwards() {
+        // A future value of 1000, discounted back at 25% a year
+        let balance = InvertibleRelation::new(1000., |x| x * 1.25, |x| x / 1.25);
+
+        let mut discounted = balance.backwards();
+
+        assert_eq!(discounted.next(), Some(800.));
+
+        assert_eq!(discounted.next(), Some(640.));
+
+        assert_eq!(discounted.inner().index(), -2);
+
+        let balance = discounted.into_inner();
+
+        assert!(balance.take(3).eq([640., 800., 1000.]));
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::{self, Write};
+
+#[cfg(feature = "parse")]
+use num_traits::Float;
+use num_traits::Num;
+use serde::Serialize;
+
+#[cfg(feature = "parse")]
+use crate::ExpressionRelation;
+use crate::{finance::Loan, ParamRelation, Relation, RelationLike};
+
+/// One term of a run, written as one line of JSON
+#[derive(Serialize)]
+pub(crate) struct Record<'a, I, P> {
+    pub(crate) index: usize,
+    pub(crate) value: &'a I,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) param: Option<&'a P>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) path: Option<usize>,
+}
+
+/// Writes `value` as JSON, followed by a line break
+pub(crate) fn write_line<T: Serialize>(writer: &mut impl Write, value: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")
+}
+
+/// Writes one line per term, with the index of each term counted from `first_index`
+pub(crate) fn write_terms<I: Serialize>(
+    mut writer: impl Write,
+    first_index: usize,
+    terms: impl Iterator<Item = I>,
+) -> io::Result<()> {
+    for (index, value) in (first_index..).zip(terms) {
+        write_line(
+            &mut writer,
+            &Record::<_, ()> {
+                index,
+                value: &value,
+                param: None,
+                path: None,
+            },
+        )?;
+    }
+
+    writer.flush()
+}
+
+impl<I: Num + Clone + Serialize, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Writes the next `count` terms as JSON Lines, beginning with the current number, without updating the struct
+    ///
+    /// Each line is an object such as `{"index":0,"value":1.5}`, written as the term is calculated
+    ///
+    /// # Errors
+    /// - If a term cannot be serialized, or writing to `writer` fails
+    pub fn export_json_lines(&self, writer: impl Write, count: usize) -> io::Result<()> {
+        RelationLike::export_json_lines(self, writer, count)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl<I: Float + Serialize> ExpressionRelation<I> {
+    /// Writes the next `count` terms as JSON Lines, like [`Relation::export_json_lines`]
+    ///
+    /// # Errors
+    /// - If a term cannot be serialized, or writing to `writer` fails
+    pub fn export_json_lines(&self, writer: impl Write, count: usize) -> io::Result<()> {
+        RelationLike::export_json_lines(self, writer, count)
+    }
+}
+
+impl<P: Clone + Serialize, I: Num + Clone + Serialize, F: FnOnce(P, I) -> I + Copy>
+    ParamRelation<P, I, F>
+{
+    /// Writes the run from the starter number for `steps` steps with each of `params` as JSON Lines,
+    /// like [`ParamRelation::sweep_trajectories`] without collecting the trajectories
+    ///
+    /// Each line is an object such as `{"index":0,"value":0.5,"param":3.2}`
+    ///
+    /// # Errors
+    /// - If a term or parameter cannot be serialized, or writing to `writer` fails
+    pub fn export_sweep_json_lines(
+        &self,
+        mut writer: impl Write,
+        params: impl IntoIterator<Item = P>,
+        steps: usize,
+    ) -> io::Result<()> {
+        for param in params {
+            let relation = self.clone().with_param(param.clone());
+
+            for (index, value) in relation.take(steps.saturating_add(1)).enumerate() {
+                write_line(
+                    &mut writer,
+                    &Record {
+                        index,
+                        value: &value,
+                        param: Some(&param),
+                        path: None,
+                    },
+                )?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+impl<I: Num + Clone + PartialOrd + Serialize> Loan<I> {
+    /// Writes the full amortization schedule as JSON Lines, with one object per period
+    ///
+    /// # Errors
+    /// - If the repayment does not exceed the first period's interest, as an error of kind [`io::ErrorKind::InvalidInput`]
+    /// - If a row cannot be serialized, or writing to `writer` fails
+    pub fn export_json_lines(&self, mut writer: impl Write) -> io::Result<()> {
+        let rows = self
+            .rows()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        for row in rows {
+            write_line(&mut writer, &row)?;
+        }
+
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn test_export_json_lines() {
+        let mut output = Vec::new();
+
+        Relation::new(1., |x| x * 1.5)
+            .export_json_lines(&mut output, 2)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"index\":0,\"value\":1.0}\n{\"index\":1,\"value\":1.5}\n"
+        );
+
+        let mut output = Vec::new();
+
+        ParamRelation::new(0, 0, |step, x| x + step)
+            .export_sweep_json_lines(&mut output, [1, 2], 1)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"index\":0,\"value\":0,\"param\":1}\n\
+             {\"index\":1,\"value\":1,\"param\":1}\n\
+             {\"index\":0,\"value\":0,\"param\":2}\n\
+             {\"index\":1,\"value\":2,\"param\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_export_schedule_json_lines() {
+        let mut output = Vec::new();
+
+        Loan::new(1000, 1, 1500)
+            .export_json_lines(&mut output)
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some(
+                "{\"period\":1,\"opening_balance\":1000,\"interest\":1000,\
+                 \"payment\":1500,\"principal\":500,\"closing_balance\":500}"
+            )
+        );
+
+        assert_eq!(lines.count(), 1);
+    }
+}
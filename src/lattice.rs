@@ -0,0 +1,126 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+/// A doubly indexed recurrence, computed one row at a time, such as Pascal's triangle
+///
+/// Each row is one entry longer than the row before it. The relation function is given the index `n` of the new row,
+/// the index `k` of the entry within it, and the previous row, and returns the entry `t(n, k)`.
+/// Only the current row is kept, so memory use is bounded by the length of a single row
+#[derive(Debug, Clone)]
+pub struct LatticeRecurrence<I, F> {
+    index: usize,
+    row: Vec<I>,
+    relation: F,
+}
+
+impl<I: Num + Clone> LatticeRecurrence<I, ()> {
+    /// Create Pascal's triangle, where `C(n, k) = C(n-1, k-1) + C(n-1, k)`, beginning with the row `[1]`
+    pub fn pascal() -> LatticeRecurrence<I, impl FnOnce(usize, usize, &[I]) -> I + Copy> {
+        LatticeRecurrence::new([I::one()], |_, k, above: &[I]| {
+            let left = k.checked_sub(1).and_then(|k| above.get(k));
+            let right = above.get(k);
+
+            left.cloned().unwrap_or_else(I::zero) + right.cloned().unwrap_or_else(I::zero)
+        })
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(usize, usize, &[I]) -> I + Copy> LatticeRecurrence<I, F> {
+    /// Create a new [`LatticeRecurrence`], where `first` is the row at index `0`
+    pub fn new(first: impl Into<Vec<I>>, relation: F) -> Self {
+        Self {
+            index: 0,
+            row: first.into(),
+            relation,
+        }
+    }
+
+    /// Returns the current row
+    pub fn row(&self) -> &[I] {
+        &self.row
+    }
+
+    /// Returns the entry at index `k` of the current row, or [`None`] if the row is not that long
+    pub fn get(&self, k: usize) -> Option<&I> {
+        self.row.get(k)
+    }
+
+    /// Returns the index of the current row
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next row
+    pub fn calculate_next(&self) -> Vec<I> {
+        (0..=self.row.len())
+            .map(|k| (self.relation)(self.index + 1, k, &self.row))
+            .collect()
+    }
+
+    /// Calculates the next row and updates the struct
+    pub fn next(&mut self) {
+        self.row = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`LatticeRecurrence::next`] `index` times, and then returns the resulting row
+    pub fn nth(&mut self, index: usize) -> Vec<I> {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.row.clone()
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(usize, usize, &[I]) -> I + Copy> Iterator
+    for LatticeRecurrence<I, F>
+{
+    type Item = Vec<I>;
+
+    /// Yields the current row and then advances the recurrence
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.row.clone();
+        LatticeRecurrence::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pascal() {
+        let mut pascal = LatticeRecurrence::<u64, _>::pascal();
+
+        assert_eq!(pascal.calculate_next(), [1, 1]);
+
+        assert_eq!(pascal.nth(4), [1, 4, 6, 4, 1]);
+
+        assert_eq!(pascal.index(), 4);
+
+        assert_eq!(pascal.nth(52).get(28), Some(&7_648_690_600_760_440));
+
+        let rows = LatticeRecurrence::<u32, _>::pascal();
+
+        assert!(rows.take(3).eq([&[1][..], &[1, 1], &[1, 2, 1]]));
+    }
+
+    #[test]
+    fn test_stirling_second_kind() {
+        // S(n, k) = k * S(n-1, k) + S(n-1, k-1)
+        let mut stirling = LatticeRecurrence::new([1u64], |_, k, above: &[u64]| {
+            let left = k.checked_sub(1).and_then(|k| above.get(k)).copied();
+
+            k as u64 * above.get(k).copied().unwrap_or(0) + left.unwrap_or(0)
+        });
+
+        assert_eq!(stirling.nth(4), [0, 1, 7, 6, 1]);
+    }
+}
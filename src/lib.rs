@@ -1,6 +1,206 @@
 #![no_std]
 
-use num_traits::Signed;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+mod acceleration;
+mod affine;
+mod analysis;
+#[cfg(feature = "ndarray")]
+mod batch;
+#[cfg(feature = "std")]
+mod channel;
+mod checked;
+#[cfg(feature = "alloc")]
+mod closed_form;
+#[cfg(feature = "alloc")]
+mod cobweb;
+#[cfg(feature = "complex")]
+mod complex;
+mod compose;
+pub mod continued_fraction;
+mod control;
+#[cfg(feature = "std")]
+mod csv;
+mod cycle;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod derived;
+mod divergence;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod error;
+mod escape;
+#[cfg(feature = "parse")]
+mod expression;
+mod fallible;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod finance;
+#[cfg(feature = "alloc")]
+mod fit;
+mod fixed_point;
+#[cfg(feature = "alloc")]
+mod forced;
+mod higher_order;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod indexed;
+mod invertible;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "alloc")]
+mod lattice;
+#[cfg(feature = "alloc")]
+mod linear;
+mod lyapunov;
+#[macro_use]
+mod macros;
+mod map;
+mod markov;
+#[cfg(feature = "nalgebra")]
+mod matrix;
+#[cfg(feature = "alloc")]
+mod metadata;
+mod observe;
+mod overflow;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod param;
+#[cfg(feature = "alloc")]
+mod period;
+#[cfg(feature = "plotters")]
+mod plot;
+#[cfg(feature = "rational")]
+mod rational;
+#[cfg(feature = "alloc")]
+mod recording;
+mod recurrence;
+mod relation_like;
+mod search;
+pub mod sequences;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simd")]
+mod simd;
+mod solver;
+#[cfg(feature = "alloc")]
+mod sparkline;
+mod state;
+mod stateful;
+mod stats;
+#[cfg(feature = "rand")]
+mod stochastic;
+#[cfg(feature = "async")]
+mod stream;
+mod system;
+#[cfg(feature = "alloc")]
+mod table;
+mod trace;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod zip;
+
+#[cfg(feature = "nalgebra")]
+pub use nalgebra;
+#[cfg(feature = "ndarray")]
+pub use ndarray;
+#[cfg(feature = "bigint")]
+pub use num_bigint;
+#[cfg(feature = "complex")]
+pub use num_complex;
+#[cfg(feature = "rational")]
+pub use num_rational;
+#[cfg(feature = "rand")]
+pub use rand;
+#[cfg(feature = "rayon")]
+pub use rayon;
+#[cfg(feature = "decimal")]
+pub use rust_decimal;
+
+pub use acceleration::{Aitken, Richardson};
+pub use affine::AffineRelation;
+pub use analysis::{autocorrelation, classify, Progression};
+#[cfg(feature = "ndarray")]
+pub use batch::BatchRelation;
+#[cfg(feature = "std")]
+pub use channel::TermSender;
+pub use checked::CheckedRelation;
+#[cfg(feature = "alloc")]
+pub use closed_form::ClosedForm;
+#[cfg(feature = "alloc")]
+pub use control::CancelToken;
+pub use control::{Interrupted, Interruption, RunControl};
+pub use cycle::Cycle;
+#[cfg(feature = "decimal")]
+pub use decimal::Rounding;
+pub use derived::{CompensatedSums, Differences, PartialSums, Ratios};
+pub use divergence::Divergence;
+#[cfg(feature = "alloc")]
+pub use dynamic::DynRelation;
+pub use error::Error;
+pub use escape::Magnitude;
+#[cfg(feature = "parse")]
+pub use expression::{Expr, ExpressionRelation, Function, Operator};
+pub use fallible::TryRelation;
+pub use fixed_point::{Basin, FixedPoint, Stability};
+#[cfg(feature = "alloc")]
+pub use forced::{ForcedClosedForm, ForcedRecurrence, Forcing, Particular};
+pub use higher_order::HigherOrderRelation;
+#[cfg(feature = "alloc")]
+pub use histogram::Histogram;
+pub use indexed::IndexedRelation;
+pub use invertible::{Backwards, InvertibleRelation};
+#[cfg(feature = "alloc")]
+pub use lattice::LatticeRecurrence;
+#[cfg(feature = "alloc")]
+pub use linear::LinearRecurrence;
+pub use map::MappedRelation;
+pub use markov::MarkovChain;
+#[cfg(feature = "nalgebra")]
+pub use matrix::AffineSystem;
+#[cfg(feature = "alloc")]
+pub use metadata::{Metadata, Named};
+pub use observe::ObservedRelation;
+pub use overflow::SaturatingNum;
+pub use param::ParamRelation;
+#[cfg(feature = "alloc")]
+pub use period::ModularPeriod;
+#[cfg(feature = "alloc")]
+pub use recording::RecordingRelation;
+pub use recurrence::Recurrence;
+pub use relation_like::RelationLike;
+#[cfg(feature = "simd")]
+pub use simd::Polynomial;
+#[cfg(feature = "alloc")]
+pub use sparkline::Sparkline;
+pub use state::RelationState;
+pub use stateful::StatefulRelation;
+pub use stats::{RunningStats, Stats};
+#[cfg(all(feature = "alloc", feature = "rand"))]
+pub use stochastic::MonteCarlo;
+#[cfg(feature = "rand")]
+pub use stochastic::StochasticRelation;
+#[cfg(feature = "async")]
+pub use stream::{RelationStream, TickedStream};
+pub use system::System;
+#[cfg(feature = "alloc")]
+pub use table::Table;
+#[cfg(feature = "wasm")]
+pub use wasm::WasmRelation;
+pub use zip::ZipWith;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{Current, Window};
+}
 
 /// A structural representation of a relation
 ///
@@ -10,36 +210,217 @@ use num_traits::Signed;
 /// The concept is based on the Mathematic Recursion and Financial Modelling concept of relations
 #[derive(Debug, Copy, Clone)]
 pub struct Relation<I, F> {
+    starter: I,
     current_number: I,
+    index: usize,
     relation: F,
 }
 
-impl<I: Signed + Copy, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
     /// Create a new [`Relation`]
     pub fn new(starter: I, relation: F) -> Self {
         Self {
+            starter: starter.clone(),
             current_number: starter,
+            index: 0,
+            relation,
+        }
+    }
+
+    /// Create a [`Relation`] which resumes from a saved [`RelationState`]
+    pub fn from_state(state: RelationState<I>, relation: F) -> Self {
+        Self {
+            starter: state.starter,
+            current_number: state.current,
+            index: state.index,
+            relation,
+        }
+    }
+
+    /// Returns the starter number, at index `0`
+    pub fn starter(&self) -> &I {
+        &self.starter
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Replaces the current number, without changing the index
+    pub fn set_current(&mut self, current: I) {
+        self.current_number = current;
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Replaces the relation function, keeping the current number and index
+    pub fn set_relation(&mut self, relation: F) {
+        self.relation = relation;
+    }
+
+    /// Replaces the relation function with one of a different type, keeping the current number and index
+    ///
+    /// This allows the rule to change mid-run, such as when an interest rate changes
+    pub fn with_relation<G: FnOnce(I) -> I + Copy>(self, relation: G) -> Relation<I, G> {
+        Relation {
+            starter: self.starter,
+            current_number: self.current_number,
+            index: self.index,
             relation,
         }
     }
 
+    /// Returns the current number and its index, which can be used to resume the relation later
+    pub fn state(&self) -> RelationState<I> {
+        RelationState {
+            starter: self.starter.clone(),
+            current: self.current_number.clone(),
+            index: self.index,
+        }
+    }
+
+    /// Saves a checkpoint of the relation, which it can later return to with [`Relation::restore`]
+    ///
+    /// This is the same as [`Relation::state`]
+    pub fn snapshot(&self) -> RelationState<I> {
+        self.state()
+    }
+
+    /// Returns the relation to a checkpoint saved by [`Relation::snapshot`], keeping the relation function
+    ///
+    /// A checkpoint can be restored any number of times, so several continuations can be explored from it
+    /// without recalculating the terms before it
+    pub fn restore(&mut self, state: RelationState<I>) {
+        self.starter = state.starter;
+        self.current_number = state.current;
+        self.index = state.index;
+    }
+
+    /// Rewinds the relation to the starter number
+    pub fn reset(&mut self) {
+        self.current_number = self.starter.clone();
+        self.index = 0;
+    }
+
+    /// Rewinds the relation and replaces the starter number with `starter`
+    pub fn restart_with(&mut self, starter: I) {
+        self.starter = starter;
+        self.reset();
+    }
+
     /// Calculates and returns the next number in the sequence
     pub fn calculate_next(&self) -> I {
-        (self.relation)(self.current_number)
+        (self.relation)(self.current_number.clone())
     }
 
     /// Calculates the next number in the sequence and updates the struct
     pub fn next(&mut self) {
         self.current_number = self.calculate_next();
+        self.index += 1;
     }
 
     /// Calls [`Relation::next`] `index` times, and then returns the result
+    ///
+    /// This advances the relation, so `index` is counted from the current number rather than the starter.
+    /// Use [`Relation::nth_calculated`] to look ahead without advancing
     pub fn nth(&mut self, index: usize) -> I {
         for _ in 0..index {
             self.next();
         }
 
-        self.current_number
+        self.current_number.clone()
+    }
+
+    /// Calculates and returns the number `index` steps after the current number, without updating the struct
+    pub fn nth_calculated(&self, index: usize) -> I {
+        let mut number = self.current_number.clone();
+
+        for _ in 0..index {
+            number = (self.relation)(number);
+        }
+
+        number
+    }
+
+    /// Returns `count` terms, beginning with the current number, without updating the struct
+    #[cfg(feature = "alloc")]
+    pub fn terms(&self, count: usize) -> Vec<I> {
+        Self::terms_from(self.current_number.clone(), self.relation, count)
+    }
+
+    /// Returns the first `count` terms, beginning with the starter number, without updating the struct
+    #[cfg(feature = "alloc")]
+    pub fn terms_from_start(&self, count: usize) -> Vec<I> {
+        Self::terms_from(self.starter.clone(), self.relation, count)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn terms_from(first: I, relation: F, count: usize) -> Vec<I> {
+        // The count may be far more than fits in memory, so only a little is reserved up front
+        let mut terms = Vec::with_capacity(count.min(1024));
+        let mut number = first;
+
+        for _ in 1..count {
+            let next = relation(number.clone());
+            terms.push(core::mem::replace(&mut number, next));
+        }
+
+        if count > 0 {
+            terms.push(number);
+        }
+
+        terms
+    }
+}
+
+/// The result of a relation converging
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Convergence<I> {
+    /// The estimated limit of the sequence
+    pub limit: I,
+    /// The number of steps taken to converge
+    pub steps: usize,
+}
+
+/// Returns the absolute difference between two numbers, without requiring them to be signed
+pub(crate) fn abs_diff<I: Num + Clone + PartialOrd>(a: &I, b: &I) -> I {
+    if a > b {
+        a.clone() - b.clone()
+    } else {
+        b.clone() - a.clone()
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calls [`Relation::next`] until successive terms differ by less than `tolerance`
+    ///
+    /// Returns the last term as the estimated limit, along with the number of steps taken
+    ///
+    /// # Errors
+    /// - If the terms are still not within `tolerance` of each other after `max_steps` steps
+    pub fn converge(&mut self, tolerance: I, max_steps: usize) -> Result<Convergence<I>, Error> {
+        RelationLike::converge(self, tolerance, max_steps)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for Relation<I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    ///
+    /// The stream never ends, so it should be bounded with an adapter such as [`Iterator::take`]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        Relation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
     }
 }
 
@@ -59,7 +440,11 @@ mod tests {
 
         assert_eq!(relation.current_number, 2);
 
-        assert_eq!(relation.nth(100), 102)
+        assert_eq!(relation.index(), 1);
+
+        assert_eq!(relation.nth(100), 102);
+
+        assert_eq!(relation.index(), 101);
     }
 
     #[test]
@@ -106,4 +491,223 @@ mod tests {
 
         assert_eq!(relation.nth(100), 2.535301200456459_e30);
     }
+
+    #[test]
+    fn test_iterator() {
+        let relation = Relation::new(1, |x| x * 2);
+
+        assert!(relation.take(5).eq([1, 2, 4, 8, 16]));
+
+        let mut evens = relation.skip(1).filter(|x| x % 4 == 0);
+
+        assert_eq!(evens.next(), Some(4));
+
+        assert_eq!(evens.next(), Some(8));
+
+        let mut sum = 0;
+        for term in relation.into_iter().take(4) {
+            sum += term;
+        }
+
+        assert_eq!(sum, 15);
+    }
+
+    #[test]
+    fn test_unsigned_relation() {
+        let mut relation = Relation::new(1u32, |x| x * 3);
+
+        assert_eq!(relation.nth(4), 81);
+
+        let mut countdown = Relation::new(10usize, |x| x.saturating_sub(3));
+
+        assert_eq!(
+            countdown.converge(1, 10),
+            Ok(Convergence { limit: 0, steps: 5 })
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_relation() {
+        use num_bigint::BigInt;
+
+        let mut relation = Relation::new(BigInt::from(1), |x| x * 3 + 1);
+
+        assert_eq!(relation.calculate_next(), BigInt::from(4));
+
+        relation.next();
+
+        assert_eq!(relation.current_number, BigInt::from(4));
+
+        assert_eq!(
+            relation.nth(100),
+            "2319198843294050989664075083945295727159483849004"
+                .parse::<BigInt>()
+                .unwrap()
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_iterator() {
+        use num_bigint::BigUint;
+
+        let relation = Relation::new(BigUint::from(2u8), |x: BigUint| &x * &x);
+
+        let term = relation.take(8).last().unwrap();
+
+        assert_eq!(term, BigUint::from(2u8).pow(128));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let mut relation: Relation<i32, fn(i32) -> i32> = Relation::new(100, |x| x * 2);
+
+        assert_eq!(relation.current(), &100);
+
+        relation.set_current(200);
+
+        assert_eq!(relation.nth(1), 400);
+
+        relation.set_relation(|x| x * 3);
+
+        assert_eq!(relation.calculate_next(), 1200);
+
+        let offset = 10;
+        let mut relation = relation.with_relation(move |x| x - offset);
+
+        assert_eq!(relation.index(), 1);
+
+        assert_eq!(relation.nth(2), 380);
+    }
+
+    #[test]
+    fn test_nth_calculated() {
+        let mut relation = Relation::new(1, |x| x + 1);
+
+        assert_eq!(relation.nth_calculated(10), 11);
+
+        assert_eq!(relation.nth_calculated(3), 4);
+
+        assert_eq!(relation.nth_calculated(0), 1);
+
+        assert_eq!(relation.index(), 0);
+
+        relation.next();
+
+        assert_eq!(relation.nth_calculated(10), relation.nth(10));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_terms() {
+        let mut relation = Relation::new(1, |x| x * 2);
+
+        assert_eq!(relation.terms(5), [1, 2, 4, 8, 16]);
+
+        assert!(relation.terms(0).is_empty());
+
+        relation.nth(3);
+
+        assert_eq!(relation.terms(3), [8, 16, 32]);
+
+        assert_eq!(relation.terms_from_start(3), [1, 2, 4]);
+
+        assert_eq!(relation.index(), 3);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut relation = Relation::new(1, |x| x * 3);
+
+        assert_eq!(relation.nth(4), 81);
+
+        relation.reset();
+
+        assert_eq!(relation.current(), &1);
+
+        assert_eq!(relation.index(), 0);
+
+        assert_eq!(relation.nth(4), 81);
+
+        relation.restart_with(2);
+
+        assert_eq!(relation.starter(), &2);
+
+        assert_eq!(relation.nth(4), 162);
+
+        relation.reset();
+
+        assert_eq!(relation.current(), &2);
+    }
+
+    #[test]
+    fn test_resume_from_state() {
+        let rule = |x| x * 2 + 1;
+        let mut relation = Relation::new(0, rule);
+        relation.nth(5);
+
+        let state = relation.state();
+
+        assert_eq!(
+            state,
+            RelationState {
+                starter: 0,
+                current: 31,
+                index: 5
+            }
+        );
+
+        let mut resumed = Relation::from_state(state, rule);
+
+        assert_eq!(resumed.index(), 5);
+
+        assert_eq!(resumed.nth(1), relation.nth(1));
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let mut relation = Relation::new(1000., |x| x * 1.1);
+        relation.nth(10);
+
+        let checkpoint = relation.snapshot();
+        let year_ten = *relation.current();
+
+        let grown = relation.nth(5);
+
+        relation.restore(checkpoint);
+
+        assert_eq!(relation.index(), 10);
+
+        assert_eq!(relation.current(), &year_ten);
+
+        // A different continuation from the same checkpoint
+        let mut declined = relation.with_relation(|x| x * 0.9);
+
+        assert!(declined.nth(5) < year_ten);
+
+        declined.restore(checkpoint);
+
+        assert_eq!(declined.nth_calculated(0), year_ten);
+
+        assert!(grown > year_ten);
+    }
+
+    #[test]
+    fn test_converge() {
+        let mut relation = Relation::new(1., |x: f64| x / 2. + 1.);
+
+        let convergence = relation.converge(1e-10, 100).unwrap();
+
+        assert!((convergence.limit - 2.).abs() < 1e-9);
+
+        assert_eq!(convergence.steps, 34);
+
+        let mut relation = Relation::new(1., |x| x * 2.);
+
+        assert_eq!(
+            relation.converge(1e-10, 100),
+            Err(Error::DidNotConverge { steps: 100 })
+        );
+    }
 }
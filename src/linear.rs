@@ -0,0 +1,417 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::recurrence::{calculate_following, shift_window};
+
+/// A square matrix, stored in row-major order
+#[derive(Debug, Clone, PartialEq)]
+struct Matrix<I> {
+    size: usize,
+    entries: Vec<I>,
+}
+
+impl<I: Num + Clone> Matrix<I> {
+    fn identity(size: usize) -> Self {
+        let entries = (0..size * size)
+            .map(|i| {
+                if i / size == i % size {
+                    I::one()
+                } else {
+                    I::zero()
+                }
+            })
+            .collect();
+
+        Self { size, entries }
+    }
+
+    fn get(&self, row: usize, column: usize) -> &I {
+        &self.entries[row * self.size + column]
+    }
+
+    /// Multiplies two matrices, applying `reduce` after every operation to keep the entries small
+    fn mul(&self, rhs: &Self, reduce: &impl Fn(I) -> I) -> Self {
+        let entries = (0..self.size * self.size)
+            .map(|i| {
+                let (row, column) = (i / self.size, i % self.size);
+
+                (0..self.size).fold(I::zero(), |sum, k| {
+                    reduce(sum + reduce(self.get(row, k).clone() * rhs.get(k, column).clone()))
+                })
+            })
+            .collect();
+
+        Self {
+            size: self.size,
+            entries,
+        }
+    }
+
+    /// Raises the matrix to the power `exponent` by repeated squaring
+    fn pow(&self, mut exponent: usize, reduce: &impl Fn(I) -> I) -> Self {
+        let mut result = Self::identity(self.size);
+        let mut base = self.clone();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base, reduce);
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0 {
+                base = base.mul(&base, reduce);
+            }
+        }
+
+        result
+    }
+
+    fn apply(&self, vector: &[I], reduce: &impl Fn(I) -> I) -> Vec<I> {
+        (0..self.size)
+            .map(|row| {
+                (0..self.size).fold(I::zero(), |sum, k| {
+                    reduce(sum + reduce(self.get(row, k).clone() * vector[k].clone()))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reduces `x` modulo `modulus`, into the range `0..modulus` even when `x` is negative
+pub(crate) fn reduce_mod<I: Num + Clone + PartialOrd>(x: I, modulus: &I) -> I {
+    let remainder = x % modulus.clone();
+
+    if remainder < I::zero() {
+        remainder + modulus.clone()
+    } else {
+        remainder
+    }
+}
+
+/// A linear recurrence of order `k`, where `t(n+k) = c1 * t(n+k-1) + c2 * t(n+k-2) + ... + ck * t(n)`
+///
+/// This covers the Fibonacci, Lucas, Pell, and tribonacci sequences, among many others.
+/// As the rule is linear, [`LinearRecurrence::nth`] jumps ahead using matrix exponentiation,
+/// taking `O(k^3 log n)` time rather than stepping `n` times
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedLinearRecurrence<I>"))]
+pub struct LinearRecurrence<I> {
+    coefficients: Vec<I>,
+    terms: Vec<I>,
+    index: usize,
+}
+
+/// A deserialized [`LinearRecurrence`], before checking it has as many terms as coefficients
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedLinearRecurrence<I> {
+    coefficients: Vec<I>,
+    terms: Vec<I>,
+    index: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<I> TryFrom<UncheckedLinearRecurrence<I>> for LinearRecurrence<I> {
+    type Error = &'static str;
+
+    fn try_from(unchecked: UncheckedLinearRecurrence<I>) -> Result<Self, Self::Error> {
+        if unchecked.coefficients.is_empty() {
+            Err("a linear recurrence requires at least one coefficient")
+        } else if unchecked.coefficients.len() != unchecked.terms.len() {
+            Err("a linear recurrence requires one term per coefficient")
+        } else {
+            Ok(Self {
+                coefficients: unchecked.coefficients,
+                terms: unchecked.terms,
+                index: unchecked.index,
+            })
+        }
+    }
+}
+
+impl<I: Num + Clone> LinearRecurrence<I> {
+    /// Create a new [`LinearRecurrence`]
+    ///
+    /// The coefficients are ordered from `c1`, which multiplies the newest term, to `ck`, which multiplies the oldest.
+    /// The initial terms are ordered from `t(0)` to `t(k-1)`
+    ///
+    /// # Panics
+    /// - If there are no coefficients, or the number of initial terms does not match the number of coefficients
+    pub fn new(coefficients: impl Into<Vec<I>>, initial: impl Into<Vec<I>>) -> Self {
+        let coefficients = coefficients.into();
+        let terms = initial.into();
+
+        assert!(
+            !coefficients.is_empty(),
+            "a linear recurrence requires at least one coefficient"
+        );
+        assert_eq!(
+            coefficients.len(),
+            terms.len(),
+            "a linear recurrence requires one initial term per coefficient"
+        );
+
+        Self {
+            coefficients,
+            terms,
+            index: 0,
+        }
+    }
+
+    /// Create a [`LinearRecurrence`] from its parts, which must already be the same length
+    pub(crate) fn from_parts(coefficients: Vec<I>, terms: Vec<I>, index: usize) -> Self {
+        Self {
+            coefficients,
+            terms,
+            index,
+        }
+    }
+
+    /// Returns the order `k` of the recurrence
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Returns the coefficients, ordered from `c1` to `ck`
+    pub fn coefficients(&self) -> &[I] {
+        &self.coefficients
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.terms[0]
+    }
+
+    /// Returns the current number, followed by the `k - 1` numbers after it
+    pub fn terms(&self) -> &[I] {
+        &self.terms
+    }
+
+    /// Returns the index of the current number, where the first initial term is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates the number after the last of [`LinearRecurrence::terms`]
+    pub(crate) fn calculate_following(&self) -> I {
+        calculate_following(&self.coefficients, &self.terms)
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        match self.terms.get(1) {
+            Some(next) => next.clone(),
+            None => self.calculate_following(),
+        }
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        let following = self.calculate_following();
+
+        self.advance_to(following);
+    }
+
+    /// Updates the struct to the next number in the sequence, given the number after the last of [`LinearRecurrence::terms`]
+    pub(crate) fn advance_to(&mut self, following: I) {
+        shift_window(&mut self.terms, following);
+        self.index += 1;
+    }
+
+    /// The companion matrix, which advances [`LinearRecurrence::terms`] by one step
+    fn step_matrix(&self) -> Matrix<I> {
+        let order = self.order();
+        let mut matrix = Matrix {
+            size: order,
+            entries: alloc::vec![I::zero(); order * order],
+        };
+
+        for row in 0..order - 1 {
+            matrix.entries[row * order + row + 1] = I::one();
+        }
+
+        for (column, coefficient) in self.coefficients.iter().rev().enumerate() {
+            matrix.entries[(order - 1) * order + column] = coefficient.clone();
+        }
+
+        matrix
+    }
+
+    /// Returns the terms `steps` steps after the current number
+    fn terms_after(&self, steps: usize) -> Vec<I> {
+        let unchanged = |x| x;
+
+        self.step_matrix()
+            .pow(steps, &unchanged)
+            .apply(&self.terms, &unchanged)
+    }
+
+    /// Advances the relation by `index` steps using matrix exponentiation, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        self.terms = self.terms_after(index);
+        self.index += index;
+
+        self.terms[0].clone()
+    }
+
+    /// Calculates and returns the number `index` steps after the current number, without updating the struct
+    pub fn nth_calculated(&self, index: usize) -> I {
+        self.terms_after(index).swap_remove(0)
+    }
+}
+
+impl<I: Num + Clone + PartialOrd> LinearRecurrence<I> {
+    /// Calculates the number `index` steps after the current number, modulo `modulus`, without updating the struct
+    ///
+    /// Every intermediate value is reduced, so huge indices can be reached without overflow,
+    /// as long as `modulus * modulus` fits in `I`. The result is always in the range `0..modulus`
+    ///
+    /// # Panics
+    /// - If `modulus` is not positive
+    pub fn nth_mod(&self, index: usize, modulus: I) -> I {
+        assert!(modulus > I::zero(), "the modulus must be positive");
+
+        let reduce = |x| reduce_mod(x, &modulus);
+
+        let mut matrix = self.step_matrix();
+        matrix.entries = matrix.entries.into_iter().map(reduce).collect();
+        let terms: Vec<I> = self.terms.iter().cloned().map(reduce).collect();
+
+        matrix
+            .pow(index, &reduce)
+            .apply(&terms, &reduce)
+            .swap_remove(0)
+    }
+}
+
+impl<I: Num + Clone> Iterator for LinearRecurrence<I> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.terms[0].clone();
+        LinearRecurrence::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci() {
+        let mut fibonacci = LinearRecurrence::new([1u64, 1], [0, 1]);
+
+        assert_eq!(fibonacci.calculate_next(), 1);
+
+        assert_eq!(fibonacci.nth_calculated(10), 55);
+
+        assert_eq!(fibonacci.nth_calculated(90), 2_880_067_194_370_816_120);
+
+        fibonacci.next();
+
+        assert_eq!(fibonacci.nth(9), 55);
+
+        assert_eq!(fibonacci.index(), 10);
+
+        assert_eq!(fibonacci.terms(), [55, 89]);
+    }
+
+    #[test]
+    fn test_matches_stepping() {
+        let recurrences = [
+            // Lucas
+            LinearRecurrence::new(alloc::vec![1i64, 1], alloc::vec![2, 1]),
+            // Pell
+            LinearRecurrence::new(alloc::vec![2, 1], alloc::vec![0, 1]),
+            // Tribonacci
+            LinearRecurrence::new(alloc::vec![1, 1, 1], alloc::vec![0, 0, 1]),
+            // Geometric
+            LinearRecurrence::new(alloc::vec![3], alloc::vec![1]),
+        ];
+
+        for recurrence in recurrences {
+            let stepped: Vec<_> = recurrence.clone().take(30).collect();
+            let jumped: Vec<_> = (0..30).map(|n| recurrence.nth_calculated(n)).collect();
+
+            assert_eq!(stepped, jumped);
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        let lucas = LinearRecurrence::new([1, 1], [2, 1]);
+
+        assert!(lucas.take(8).eq([2, 1, 3, 4, 7, 11, 18, 29]));
+
+        let pell = LinearRecurrence::new([2, 1], [0, 1]);
+
+        assert!(pell.take(7).eq([0, 1, 2, 5, 12, 29, 70]));
+
+        let tribonacci = LinearRecurrence::new([1, 1, 1], [0, 0, 1]);
+
+        assert!(tribonacci.take(8).eq([0, 0, 1, 1, 2, 4, 7, 13]));
+    }
+
+    #[test]
+    fn test_nth_mod() {
+        let fibonacci = LinearRecurrence::new([1u64, 1], [0, 1]);
+
+        assert_eq!(
+            fibonacci.nth_mod(90, 1_000_000_007),
+            2_880_067_194_370_816_120 % 1_000_000_007
+        );
+
+        assert_eq!(
+            fibonacci.nth_mod(1_000_000_000_000_000_000, 1_000_000_007),
+            209_783_453
+        );
+
+        // Negative coefficients, where t(n) = 3^n - 2^n
+        let recurrence = LinearRecurrence::new([5i64, -6], [0, 1]);
+
+        assert_eq!(
+            recurrence.nth_mod(20, 1000),
+            (3i64.pow(20) - 2i64.pow(20)) % 1000
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_linear_recurrence_round_trip() {
+        let mut lucas = LinearRecurrence::new([1i64, 1], [2, 1]);
+        lucas.nth(4);
+
+        let json = serde_json::to_string(&lucas).unwrap();
+
+        assert_eq!(json, r#"{"coefficients":[1,1],"terms":[7,11],"index":4}"#);
+
+        let resumed: LinearRecurrence<i64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(resumed, lucas);
+
+        assert!(serde_json::from_str::<LinearRecurrence<i64>>(
+            r#"{"coefficients":[1,1],"terms":[7],"index":4}"#
+        )
+        .is_err());
+
+        assert!(serde_json::from_str::<LinearRecurrence<i64>>(
+            r#"{"coefficients":[],"terms":[],"index":0}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_initial_terms() {
+        LinearRecurrence::new([1, 1], [0]);
+    }
+}
@@ -0,0 +1,49 @@
+use num_traits::Float;
+
+use crate::Relation;
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Estimates the Lyapunov exponent of the relation, from the current number, without updating the struct
+    ///
+    /// This is the average of `ln |f'(x)|` over `steps` terms after discarding the first `transient` terms,
+    /// using [`Relation::derivative_at`]. A positive exponent signals chaos, where nearby starters separate exponentially,
+    /// and a negative exponent signals that the relation settles onto a stable fixed point or cycle.
+    /// Returns [`None`] if `steps` is zero, as there is nothing to average
+    pub fn lyapunov_exponent(&self, transient: usize, steps: usize) -> Option<I> {
+        if steps == 0 {
+            return None;
+        }
+
+        let mut term = self.nth_calculated(transient);
+        let mut total = I::zero();
+
+        for _ in 0..steps {
+            total = total + self.derivative_at(term).abs().ln();
+            term = (self.relation)(term);
+        }
+
+        Some(total / I::from(steps)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lyapunov_exponent() {
+        // The fully chaotic logistic map has an exponent of ln 2
+        let chaotic = Relation::new(0.1, |x: f64| 4. * x * (1. - x));
+
+        assert!((chaotic.lyapunov_exponent(100, 100_000).unwrap() - 2f64.ln()).abs() < 0.01);
+
+        // With a stable fixed point at 0.6, the exponent is ln |f'(0.6)| = ln 0.5
+        let stable = Relation::new(0.1, |x: f64| 2.5 * x * (1. - x));
+
+        assert!((stable.lyapunov_exponent(1000, 1000).unwrap() - 0.5f64.ln()).abs() < 1e-6);
+
+        assert_eq!(stable.index(), 0);
+
+        assert_eq!(stable.lyapunov_exponent(10, 0), None);
+    }
+}
@@ -0,0 +1,119 @@
+use core::ops::{Index, Sub};
+
+/// Create a [`Relation`](crate::Relation) or [`HigherOrderRelation`](crate::HigherOrderRelation)
+/// using the notation of recursion
+///
+/// The rule is written in terms of `t[n]`, `t[n - 1]` and so on, followed by the starting terms.
+/// A single starting term creates a [`Relation`](crate::Relation), while `K` starting terms create a
+/// [`HigherOrderRelation`](crate::HigherOrderRelation) over the last `K` terms
+///
+/// ```
+/// use relation::relation;
+///
+/// let mut relation = relation!(t[n + 1] = 2 * t[n] + 3; t[0] = 5);
+/// assert_eq!(relation.nth(2), 29);
+///
+/// let mut fibonacci = relation!(t[n + 1] = t[n] + t[n - 1]; t[0] = 0; t[1] = 1);
+/// assert_eq!(fibonacci.nth(9), 55);
+/// ```
+#[macro_export]
+macro_rules! relation {
+    ($t:ident [$n:ident + 1] = $rule:expr; $t0:ident [$i:literal] = $start:expr $(;)?) => {
+        $crate::Relation::new($start, |term| {
+            let terms = [term];
+            let $t = $crate::__private::Window(&terms);
+            let $n = $crate::__private::Current;
+            $rule
+        })
+    };
+    ($t:ident [$n:ident + 1] = $rule:expr; $($t0:ident [$i:literal] = $start:expr);+ $(;)?) => {{
+        const {
+            let indices: &[usize] = &[$($i),+];
+            let mut i = 0;
+            while i < indices.len() {
+                assert!(indices[i] == i, "starting terms must be given in order from t[0]");
+                i += 1;
+            }
+        }
+
+        $crate::HigherOrderRelation::new([$($start),+], |terms| {
+            let $t = $crate::__private::Window(&terms);
+            let $n = $crate::__private::Current;
+            $rule
+        })
+    }};
+}
+
+/// The index `n` of the current term, written as `t[n]` in [`relation!`]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Current;
+
+/// A term before the current term, written as `t[n - lag]` in [`relation!`]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Lag(usize);
+
+impl Sub<usize> for Current {
+    type Output = Lag;
+
+    fn sub(self, lag: usize) -> Lag {
+        Lag(lag)
+    }
+}
+
+/// The window of terms given to a rule in [`relation!`], ordered from oldest to newest
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Window<'a, I, const K: usize>(pub &'a [I; K]);
+
+impl<I, const K: usize> Index<Current> for Window<'_, I, K> {
+    type Output = I;
+
+    fn index(&self, _: Current) -> &I {
+        &self.0[K - 1]
+    }
+}
+
+impl<I, const K: usize> Index<Lag> for Window<'_, I, K> {
+    type Output = I;
+
+    /// # Panics
+    /// - If the lag reaches further back than the starting terms
+    fn index(&self, Lag(lag): Lag) -> &I {
+        assert!(
+            lag < K,
+            "t[n - {lag}] requires at least {} starting terms",
+            lag + 1
+        );
+
+        &self.0[K - 1 - lag]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_first_order_macro() {
+        let mut relation = relation!(t[n + 1] = 2 * t[n] + 3; t[0] = 5);
+
+        assert_eq!(relation.calculate_next(), 13);
+
+        assert_eq!(relation.nth(3), 61);
+    }
+
+    #[test]
+    fn test_higher_order_macro() {
+        let relation = relation!(t[n + 1] = t[n] + 2 * t[n - 2]; t[0] = 1; t[1] = 1; t[2] = 1);
+
+        assert!(relation.take(6).eq([1, 3, 5, 7, 13, 23]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lag_out_of_range() {
+        let mut relation = relation!(t[n + 1] = t[n] + t[n - 2]; t[0] = 0; t[1] = 1);
+
+        relation.next();
+    }
+}
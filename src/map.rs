@@ -0,0 +1,115 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// A relation whose terms are transformed as they are read, without changing the terms it calculates
+///
+/// Created by [`Relation::map`]. The transform can change the type of the terms,
+/// such as rounding an [`f64`] balance to whole cents
+#[derive(Debug, Copy, Clone)]
+pub struct MappedRelation<I, F, M> {
+    relation: Relation<I, F>,
+    transform: M,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Transforms each term with `transform` as it is read, while the relation keeps calculating with the original terms
+    ///
+    /// This shadows [`Iterator::map`], and yields the same terms when iterated
+    pub fn map<O, M: FnOnce(I) -> O + Copy>(self, transform: M) -> MappedRelation<I, F, M> {
+        MappedRelation {
+            relation: self,
+            transform,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, O, M: FnOnce(I) -> O + Copy>
+    MappedRelation<I, F, M>
+{
+    /// Returns the transformed current number
+    pub fn current(&self) -> O {
+        (self.transform)(self.relation.current_number.clone())
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation, with its untransformed terms
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Calculates and returns the transformed next number, without updating the struct
+    pub fn calculate_next(&self) -> O {
+        (self.transform)(self.relation.calculate_next())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.relation.next();
+    }
+
+    /// Calls [`MappedRelation::next`] `index` times, and then returns the transformed result
+    pub fn nth(&mut self, index: usize) -> O {
+        (self.transform)(self.relation.nth(index))
+    }
+
+    /// Discards the transform, returning the underlying relation
+    pub fn into_inner(self) -> Relation<I, F> {
+        self.relation
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, O, M: FnOnce(I) -> O + Copy> Iterator
+    for MappedRelation<I, F, M>
+{
+    type Item = O;
+
+    /// Yields the transformed current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        MappedRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let balance = Relation::new(100., |x| x * 1.015);
+
+        let mut cents = balance.map(|x: f64| (x * 100.).round() as i64);
+
+        assert_eq!(cents.current(), 10_000);
+
+        assert_eq!(cents.calculate_next(), 10_150);
+
+        assert_eq!(cents.nth(2), 10_302);
+
+        // The underlying balance is not rounded
+        assert_eq!(cents.inner().current(), &(100. * 1.015 * 1.015));
+
+        assert!(cents.take(2).eq([10_302, 10_457]));
+    }
+
+    #[test]
+    fn test_map_into_inner() {
+        let mut relation = Relation::new(1, |x| x + 1).map(|x| x * 10);
+
+        relation.next();
+
+        assert_eq!(relation.index(), 1);
+
+        assert_eq!(relation.into_inner().current(), &2);
+    }
+}
@@ -0,0 +1,124 @@
+use num_traits::Float;
+
+use crate::{Error, System};
+
+/// A Markov chain over `N` states, which evolves a probability distribution by `π(n+1) = π(n) * P`
+///
+/// The entry `P[i][j]` of the transition matrix is the probability of moving from state `i` to state `j`,
+/// so each row is a probability distribution
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MarkovChain<I, const N: usize> {
+    transition: [[I; N]; N],
+}
+
+impl<I: Float, const N: usize> MarkovChain<I, N> {
+    /// Create a new [`MarkovChain`] from its transition matrix
+    ///
+    /// # Errors
+    /// - If a row has a negative or non-finite entry, or its sum is not within the square root of machine epsilon of one
+    pub fn new(transition: [[I; N]; N]) -> Result<Self, Error> {
+        let tolerance = I::epsilon().sqrt();
+
+        for (row, probabilities) in transition.iter().enumerate() {
+            let sum = probabilities.iter().fold(I::zero(), |sum, &p| sum + p);
+
+            // Checking for non-finite entries catches `NaN`, which would pass both comparisons
+            let invalid = |p: I| !p.is_finite() || p < I::zero();
+
+            if probabilities.iter().any(|&p| invalid(p)) || (sum - I::one()).abs() > tolerance {
+                return Err(Error::NotStochastic { row });
+            }
+        }
+
+        Ok(Self { transition })
+    }
+
+    /// Returns the transition matrix
+    pub fn transition(&self) -> &[[I; N]; N] {
+        &self.transition
+    }
+
+    /// Returns the distribution one step after `distribution`
+    pub fn step(&self, distribution: &[I; N]) -> [I; N] {
+        core::array::from_fn(|j| {
+            distribution
+                .iter()
+                .zip(&self.transition)
+                .fold(I::zero(), |sum, (&p, row)| sum + p * row[j])
+        })
+    }
+
+    /// Returns the [`System`] of distributions beginning with `initial`
+    pub fn distribution(
+        &self,
+        initial: [I; N],
+    ) -> System<I, impl FnOnce([I; N]) -> [I; N] + Copy, N> {
+        let chain = *self;
+
+        System::new(initial, move |distribution| chain.step(&distribution))
+    }
+
+    /// Finds the stationary distribution, which is unchanged by a step of the chain,
+    /// by iterating from the uniform distribution until successive distributions differ by less than `tolerance`
+    ///
+    /// # Errors
+    /// - If the distribution does not settle within `max_steps` steps, such as for a periodic chain
+    pub fn stationary(&self, tolerance: I, max_steps: usize) -> Result<[I; N], Error> {
+        let uniform = I::from(N).map_or(I::zero(), |n| I::one() / n);
+
+        self.distribution([uniform; N])
+            .converge(tolerance, max_steps)
+            .map(|convergence| convergence.limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markov_chain() {
+        let weather = MarkovChain::new([[0.9, 0.1], [0.5, 0.5]]).unwrap();
+
+        assert_eq!(weather.step(&[1., 0.]), [0.9, 0.1]);
+
+        let [sunny, rainy] = weather.distribution([1., 0.]).nth(2);
+
+        assert!((sunny - 0.86).abs() < 1e-12);
+
+        assert!((rainy - 0.14).abs() < 1e-12);
+
+        let [sunny, rainy] = weather.stationary(1e-12, 1000).unwrap();
+
+        assert!((sunny - 5. / 6.).abs() < 1e-10);
+
+        assert!((rainy - 1. / 6.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_and_periodic_chains() {
+        assert_eq!(
+            MarkovChain::new([[0.5, 0.5], [0.7, 0.2]]),
+            Err(Error::NotStochastic { row: 1 })
+        );
+
+        assert_eq!(
+            MarkovChain::new([[1.5, -0.5], [0.5, 0.5]]),
+            Err(Error::NotStochastic { row: 0 })
+        );
+
+        assert_eq!(
+            MarkovChain::new([[f64::NAN, 1.], [0., 1.]]),
+            Err(Error::NotStochastic { row: 0 })
+        );
+
+        // The uniform distribution is already stationary for a chain which swaps its two states
+        let swap = MarkovChain::new([[0., 1.], [1., 0.]]).unwrap();
+
+        assert_eq!(swap.stationary(1e-12, 100), Ok([0.5, 0.5]));
+
+        let oscillating = swap.distribution([1., 0.]);
+
+        assert!(oscillating.take(3).eq([[1., 0.], [0., 1.], [1., 0.]]));
+    }
+}
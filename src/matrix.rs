@@ -0,0 +1,240 @@
+use nalgebra::{
+    allocator::Allocator, ClosedAddAssign, ClosedMulAssign, ClosedSubAssign, Complex, ComplexField,
+    Const, DefaultAllocator, DimDiff, DimSub, RealField, SMatrix, SVector, Scalar, U1,
+};
+use num_traits::Num;
+
+use crate::{Convergence, Error};
+
+/// A system of `N` coupled relations of the form `x(n+1) = A * x(n) + b`, where `A` is a matrix and `x` and `b` are vectors
+///
+/// The multi-dimensional counterpart of [`AffineRelation`](crate::AffineRelation),
+/// whose eigenvalues decide whether the system settles on a fixed point
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AffineSystem<T, const N: usize> {
+    current: SVector<T, N>,
+    index: usize,
+    matrix: SMatrix<T, N, N>,
+    offset: SVector<T, N>,
+}
+
+impl<T: Scalar + Num + ClosedAddAssign + ClosedMulAssign, const N: usize> AffineSystem<T, N> {
+    /// Create a new [`AffineSystem`] with the rule `x(n+1) = matrix * x(n) + offset`
+    pub fn new(starter: SVector<T, N>, matrix: SMatrix<T, N, N>, offset: SVector<T, N>) -> Self {
+        Self {
+            current: starter,
+            index: 0,
+            matrix,
+            offset,
+        }
+    }
+
+    /// Create a new linear [`AffineSystem`] with the rule `x(n+1) = matrix * x(n)`
+    pub fn linear(starter: SVector<T, N>, matrix: SMatrix<T, N, N>) -> Self {
+        Self::new(starter, matrix, SVector::zeros())
+    }
+
+    /// Returns the matrix `A` of the rule
+    pub fn matrix(&self) -> &SMatrix<T, N, N> {
+        &self.matrix
+    }
+
+    /// Returns the offset `b` of the rule
+    pub fn offset(&self) -> &SVector<T, N> {
+        &self.offset
+    }
+
+    /// Returns the current state
+    pub fn current(&self) -> &SVector<T, N> {
+        &self.current
+    }
+
+    /// Returns the index of the current state, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next state
+    pub fn calculate_next(&self) -> SVector<T, N> {
+        &self.matrix * &self.current + &self.offset
+    }
+
+    /// Calculates the next state and updates the struct
+    pub fn next(&mut self) {
+        self.current = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`AffineSystem::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> SVector<T, N> {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current.clone()
+    }
+}
+
+impl<T: RealField + Copy, const N: usize> AffineSystem<T, N> {
+    /// Returns the eigenvalues of the matrix `A`, which may be complex
+    pub fn eigenvalues(&self) -> SVector<Complex<T>, N>
+    where
+        Const<N>: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<Const<N>, DimDiff<Const<N>, U1>> + Allocator<DimDiff<Const<N>, U1>>,
+    {
+        self.matrix.complex_eigenvalues()
+    }
+
+    /// Returns the spectral radius of the matrix `A`, which is the largest modulus of its eigenvalues
+    ///
+    /// The system converges to its fixed point from every starting state when this is less than `1`,
+    /// and the smaller it is, the faster it converges
+    pub fn spectral_radius(&self) -> T
+    where
+        Const<N>: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<Const<N>, DimDiff<Const<N>, U1>> + Allocator<DimDiff<Const<N>, U1>>,
+    {
+        self.eigenvalues()
+            .iter()
+            .map(|eigenvalue| eigenvalue.modulus())
+            .fold(T::zero(), T::max)
+    }
+
+    /// Returns whether the system converges to its fixed point from every starting state
+    pub fn is_stable(&self) -> bool
+    where
+        Const<N>: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<Const<N>, DimDiff<Const<N>, U1>> + Allocator<DimDiff<Const<N>, U1>>,
+    {
+        self.spectral_radius() < T::one()
+    }
+
+    /// Returns the fixed point `x = (I - A)^-1 * b`, or [`None`] if `I - A` is not invertible
+    pub fn fixed_point(&self) -> Option<SVector<T, N>> {
+        (SMatrix::identity() - self.matrix)
+            .try_inverse()
+            .map(|inverse| inverse * self.offset)
+    }
+
+    /// Calls [`AffineSystem::next`] until successive states are less than `tolerance` apart
+    ///
+    /// Returns the last state as the estimated limit, along with the number of steps taken
+    ///
+    /// # Errors
+    /// - If the states are still not within `tolerance` of each other after `max_steps` steps
+    pub fn converge(
+        &mut self,
+        tolerance: T,
+        max_steps: usize,
+    ) -> Result<Convergence<SVector<T, N>>, Error>
+    where
+        T: ClosedSubAssign,
+    {
+        for steps in 1..=max_steps {
+            let previous = self.current;
+            self.next();
+
+            if (self.current - previous).norm() < tolerance {
+                return Ok(Convergence {
+                    limit: self.current,
+                    steps,
+                });
+            }
+        }
+
+        Err(Error::DidNotConverge { steps: max_steps })
+    }
+}
+
+impl<T: Scalar + Num + ClosedAddAssign + ClosedMulAssign, const N: usize> Iterator
+    for AffineSystem<T, N>
+{
+    type Item = SVector<T, N>;
+
+    /// Yields the current state and then advances the system
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.clone();
+        AffineSystem::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Matrix2, Vector2};
+
+    use super::*;
+
+    #[test]
+    fn test_affine_system() {
+        let mut system = AffineSystem::new(
+            Vector2::new(1, 0),
+            Matrix2::new(1, 1, 1, 0),
+            Vector2::new(0, 1),
+        );
+
+        assert_eq!(system.calculate_next(), Vector2::new(1, 2));
+
+        assert_eq!(system.nth(2), Vector2::new(3, 2));
+
+        assert_eq!(system.index(), 2);
+
+        let fibonacci = AffineSystem::linear(Vector2::new(1, 0), Matrix2::new(1, 1, 1, 0));
+
+        assert!(fibonacci.map(|x| x[1]).take(6).eq([0, 1, 1, 2, 3, 5]));
+    }
+
+    #[test]
+    fn test_stable_system() {
+        let mut system = AffineSystem::new(
+            Vector2::zeros(),
+            Matrix2::new(0.5, 0.25, 0.25, 0.5),
+            Vector2::new(0., 1.),
+        );
+
+        assert!((system.spectral_radius() - 0.75).abs() < 1e-12);
+
+        assert!(system.is_stable());
+
+        let fixed_point = system.fixed_point().unwrap();
+
+        assert!((fixed_point - Vector2::new(4. / 3., 8. / 3.)).norm() < 1e-12);
+
+        let convergence = system.converge(1e-12, 1000).unwrap();
+
+        assert!((convergence.limit - fixed_point).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_unstable_system() {
+        // A rotation keeps every state the same distance from the origin, so it never settles
+        let mut rotation =
+            AffineSystem::linear(Vector2::new(1., 0.), Matrix2::new(0., -1., 1., 0.));
+
+        let eigenvalues = rotation.eigenvalues();
+
+        assert!(eigenvalues
+            .iter()
+            .all(|eigenvalue| eigenvalue.re.abs() < 1e-12));
+
+        assert!((rotation.spectral_radius() - 1.).abs() < 1e-12);
+
+        assert!(!rotation.is_stable());
+
+        assert_eq!(
+            rotation.converge(1e-6, 100),
+            Err(Error::DidNotConverge { steps: 100 })
+        );
+
+        let growth = AffineSystem::linear(Vector2::new(1., 1.), Matrix2::new(1., 0., 0., 2.));
+
+        assert_eq!(growth.fixed_point(), None);
+    }
+}
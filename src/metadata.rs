@@ -0,0 +1,207 @@
+use alloc::string::String;
+use core::ops::{Deref, DerefMut};
+
+use num_traits::Num;
+
+use crate::{Differences, MappedRelation, PartialSums, Ratios, Relation, Table};
+
+/// A description of what the terms of a relation represent
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata {
+    /// A short name for the series, such as `Balance`
+    pub label: Option<String>,
+    /// A longer description of the series
+    pub description: Option<String>,
+    /// The unit of the terms, such as `AUD`
+    pub unit: Option<String>,
+}
+
+impl Metadata {
+    /// Create new [`Metadata`] with a label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the heading for a column of the terms, which is the label followed by the unit,
+    /// or `fallback` in place of a missing label
+    pub fn heading(&self, fallback: &str) -> String {
+        let label = self.label.as_deref().unwrap_or(fallback);
+
+        match &self.unit {
+            Some(unit) => alloc::format!("{label} ({unit})"),
+            None => String::from(label),
+        }
+    }
+}
+
+/// A relation, or a relation derived from one, with [`Metadata`] attached
+///
+/// Created by [`Relation::named`]. It dereferences to the relation it wraps, and the metadata
+/// is carried through to the relations derived from it, and to tables and exports of its terms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Named<R> {
+    relation: R,
+    metadata: Metadata,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Attaches [`Metadata`] with `label` to the relation
+    pub fn named(self, label: impl Into<String>) -> Named<Self> {
+        Named::new(self, Metadata::new(label))
+    }
+}
+
+impl<R> Named<R> {
+    /// Attaches `metadata` to `relation`
+    pub fn new(relation: R, metadata: Metadata) -> Self {
+        Self { relation, metadata }
+    }
+
+    /// Sets the description of the relation
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.metadata.description = Some(description.into());
+        self
+    }
+
+    /// Sets the unit of the relation's terms
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.metadata.unit = Some(unit.into());
+        self
+    }
+
+    /// Returns the metadata
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the wrapped relation
+    pub fn inner(&self) -> &R {
+        &self.relation
+    }
+
+    /// Discards the metadata, returning the wrapped relation, such as to iterate over its terms
+    pub fn into_inner(self) -> R {
+        self.relation
+    }
+
+    /// Returns the wrapped relation and its metadata
+    pub fn into_parts(self) -> (R, Metadata) {
+        (self.relation, self.metadata)
+    }
+
+    /// Derives another relation from the wrapped one, keeping the metadata
+    pub fn map_inner<S>(self, derive: impl FnOnce(R) -> S) -> Named<S> {
+        Named {
+            relation: derive(self.relation),
+            metadata: self.metadata,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Named<Relation<I, F>> {
+    /// Runs [`Relation::then`], keeping the metadata
+    pub fn then<G: FnOnce(I) -> I + Copy>(
+        self,
+        rule: G,
+    ) -> Named<Relation<I, impl FnOnce(I) -> I + Copy>> {
+        self.map_inner(|relation| relation.then(rule))
+    }
+
+    /// Runs [`Relation::compose`], keeping the metadata
+    pub fn compose<G: FnOnce(I) -> I + Copy>(
+        self,
+        rule: G,
+    ) -> Named<Relation<I, impl FnOnce(I) -> I + Copy>> {
+        self.map_inner(|relation| relation.compose(rule))
+    }
+
+    /// Runs [`Relation::map`], keeping the metadata
+    pub fn map<O, M: FnOnce(I) -> O + Copy>(self, transform: M) -> Named<MappedRelation<I, F, M>> {
+        self.map_inner(|relation| relation.map(transform))
+    }
+
+    /// Runs [`Relation::partial_sums`], keeping the metadata
+    pub fn partial_sums(self) -> Named<PartialSums<I, F>> {
+        self.map_inner(Relation::partial_sums)
+    }
+
+    /// Runs [`Relation::differences`], keeping the metadata
+    pub fn differences(self) -> Named<Differences<I, F>> {
+        self.map_inner(Relation::differences)
+    }
+
+    /// Runs [`Relation::ratios`], keeping the metadata
+    pub fn ratios(self) -> Named<Ratios<I, F>> {
+        self.map_inner(Relation::ratios)
+    }
+
+    /// Runs [`Relation::table`], with the metadata used to head the column of terms
+    pub fn table(&self, count: usize) -> Table<I> {
+        self.relation
+            .table(count)
+            .with_metadata(self.metadata.clone())
+    }
+}
+
+impl<R> Deref for Named<R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.relation
+    }
+}
+
+impl<R> DerefMut for Named<R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.relation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_named_relation() {
+        let mut balance = Relation::new(100, |x| x * 2)
+            .named("Balance")
+            .with_description("A balance doubling each year")
+            .with_unit("AUD");
+
+        assert_eq!(balance.nth(3), 800);
+
+        assert_eq!(balance.index(), 3);
+
+        assert_eq!(balance.metadata().heading("t(n)"), "Balance (AUD)");
+
+        let sums = balance.clone().then(|x| x + 1).partial_sums();
+
+        assert_eq!(sums.metadata(), balance.metadata());
+
+        assert!(sums.into_inner().take(2).eq([800, 2401]));
+    }
+
+    #[test]
+    fn test_named_table() {
+        let table = Relation::new(1, |x| x * 10).named("Count").table(2);
+
+        assert_eq!(
+            table.to_string(),
+            "n | Count\n\
+             --+------\n\
+             0 |     1\n\
+             1 |    10\n"
+        );
+
+        let unlabelled =
+            Named::new(Relation::new(1, |x| x + 1), Metadata::default()).with_unit("m");
+
+        assert_eq!(unlabelled.metadata().heading("t(n)"), "t(n) (m)");
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::{Convergence, Error, ParamRelation, Relation, RelationLike};
+
+/// A relation which calls a hook with the index and value of every term it steps to
+///
+/// Created by [`Relation::on_step`]. The hook is called by [`ObservedRelation::next`],
+/// and so by every method built on it, which suits progress bars, logging, and live plotting
+#[derive(Debug, Copy, Clone)]
+pub struct ObservedRelation<I, F, H> {
+    relation: Relation<I, F>,
+    hook: H,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calls `hook` with the index and value of each new term as the relation steps to it
+    pub fn on_step<H: FnMut(usize, &I)>(self, hook: H) -> ObservedRelation<I, F, H> {
+        ObservedRelation {
+            relation: self,
+            hook,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, H: FnMut(usize, &I)> ObservedRelation<I, F, H> {
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the underlying relation
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Calculates and returns the next number in the sequence, without updating the struct or calling the hook
+    pub fn calculate_next(&self) -> I {
+        self.relation.calculate_next()
+    }
+
+    /// Calculates the next number in the sequence, updates the struct, and calls the hook with it
+    pub fn next(&mut self) {
+        self.relation.next();
+        (self.hook)(self.relation.index, &self.relation.current_number);
+    }
+
+    /// Calls [`ObservedRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.relation.current_number.clone()
+    }
+
+    /// Discards the hook, returning the underlying relation
+    pub fn into_inner(self) -> Relation<I, F> {
+        self.relation
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy, H: FnMut(usize, &I)>
+    ObservedRelation<I, F, H>
+{
+    /// Calls [`ObservedRelation::next`] until successive terms differ by less than `tolerance`,
+    /// like [`Relation::converge`]
+    ///
+    /// # Errors
+    /// - If the terms are still not within `tolerance` of each other after `max_steps` steps
+    pub fn converge(&mut self, tolerance: I, max_steps: usize) -> Result<Convergence<I>, Error> {
+        RelationLike::converge(self, tolerance, max_steps)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, H: FnMut(usize, &I)> Iterator
+    for ObservedRelation<I, F, H>
+{
+    type Item = I;
+
+    /// Yields the current number and then advances the relation, calling the hook with the new term
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.relation.current_number.clone();
+        ObservedRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<P: Clone, I: Num + Clone, F: FnOnce(P, I) -> I + Copy> ParamRelation<P, I, F> {
+    /// Runs [`ParamRelation::sweep`], calling `hook` with the parameter, index, and value of every term stepped to
+    #[cfg(feature = "alloc")]
+    pub fn sweep_observed(
+        &self,
+        params: impl IntoIterator<Item = P>,
+        steps: usize,
+        mut hook: impl FnMut(&P, usize, &I),
+    ) -> Vec<(P, I)> {
+        params
+            .into_iter()
+            .map(|param| {
+                let mut relation = self.clone().with_param(param.clone());
+
+                for _ in 0..steps {
+                    relation.next();
+                    hook(&param, relation.index(), relation.current());
+                }
+
+                (param, relation.current().clone())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_step() {
+        let mut steps = 0;
+        let mut last = (0, 0);
+
+        let mut relation = Relation::new(1, |x| x * 2).on_step(|index, &value| {
+            steps += 1;
+            last = (index, value);
+        });
+
+        assert_eq!(relation.nth(10), 1024);
+
+        assert_eq!(relation.index(), 10);
+
+        assert_eq!(steps, 10);
+
+        assert_eq!(last, (10, 1024));
+    }
+
+    #[test]
+    fn test_observed_convergence() {
+        let mut largest_index = 0;
+
+        let result = Relation::new(1., |x: f64| x / 2.)
+            .on_step(|index, _| largest_index = index)
+            .converge(1e-6, 100);
+
+        assert_eq!(result.map(|convergence| convergence.steps), Ok(20));
+
+        assert_eq!(largest_index, 20);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sweep_observed() {
+        let growth = ParamRelation::new(0, 1, |rate, x| x * (1 + rate));
+        let mut calls = Vec::new();
+
+        let results = growth.sweep_observed([1, 2], 2, |&rate, index, &value| {
+            calls.push((rate, index, value));
+        });
+
+        assert_eq!(results, [(1, 4), (2, 9)]);
+
+        assert_eq!(calls, [(1, 1, 2), (1, 2, 4), (2, 1, 3), (2, 2, 9)]);
+    }
+}
@@ -0,0 +1,178 @@
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use num_traits::{
+    Bounded, CheckedDiv, CheckedRem, Num, One, SaturatingAdd, SaturatingMul, SaturatingSub, Zero,
+};
+
+/// An integer whose arithmetic saturates at its bounds instead of overflowing
+///
+/// Using this as the number type of a relation clamps every step to the range of the integer.
+/// For arithmetic that wraps around instead, such as a hardware counter, use [`Wrapping`](core::num::Wrapping)
+///
+/// This is separate from [`core::num::Saturating`] because `num_traits` does not implement [`Num`] for that type,
+/// and the orphan rule stops this crate from doing so, so it cannot be the number type of a relation.
+/// Unlike it, the right hand side of an operation may also be a plain integer, so rules can be written as `x * 3 + 1`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingNum<T>(pub T);
+
+impl<T: SaturatingAdd> Add for SaturatingNum<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        SaturatingNum(self.0.saturating_add(&rhs.0))
+    }
+}
+
+impl<T: SaturatingSub> Sub for SaturatingNum<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        SaturatingNum(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+impl<T: SaturatingMul> Mul for SaturatingNum<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        SaturatingNum(self.0.saturating_mul(&rhs.0))
+    }
+}
+
+impl<T: CheckedDiv + Bounded + Zero> Div for SaturatingNum<T> {
+    type Output = Self;
+
+    /// # Panics
+    /// - If `rhs` is zero
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.0.is_zero(), "attempt to divide by zero");
+
+        // The only overflowing division is `MIN / -1`, which saturates to `MAX`
+        SaturatingNum(self.0.checked_div(&rhs.0).unwrap_or_else(T::max_value))
+    }
+}
+
+impl<T: CheckedRem + Zero> Rem for SaturatingNum<T> {
+    type Output = Self;
+
+    /// # Panics
+    /// - If `rhs` is zero
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            !rhs.0.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+
+        // The only overflowing remainder is `MIN % -1`, which is mathematically zero
+        SaturatingNum(self.0.checked_rem(&rhs.0).unwrap_or_else(T::zero))
+    }
+}
+
+macro_rules! impl_scalar_op {
+    ($($op:ident $method:ident $bound:ident),+) => {
+        $(
+            impl<T> $op<T> for SaturatingNum<T>
+            where
+                SaturatingNum<T>: $op<Output = SaturatingNum<T>>,
+                T: $bound,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: T) -> Self {
+                    self.$method(SaturatingNum(rhs))
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_op!(
+    Add add SaturatingAdd,
+    Sub sub SaturatingSub,
+    Mul mul SaturatingMul,
+    Div div CheckedDiv,
+    Rem rem CheckedRem
+);
+
+impl<T: Zero + SaturatingAdd> Zero for SaturatingNum<T> {
+    fn zero() -> Self {
+        SaturatingNum(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One + SaturatingMul> One for SaturatingNum<T> {
+    fn one() -> Self {
+        SaturatingNum(T::one())
+    }
+}
+
+impl<T> Num for SaturatingNum<T>
+where
+    T: Num + SaturatingAdd + SaturatingSub + SaturatingMul + CheckedDiv + CheckedRem + Bounded,
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(string, radix).map(SaturatingNum)
+    }
+}
+
+impl<T: Bounded> Bounded for SaturatingNum<T> {
+    fn min_value() -> Self {
+        SaturatingNum(T::min_value())
+    }
+
+    fn max_value() -> Self {
+        SaturatingNum(T::max_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    use crate::Relation;
+
+    use super::*;
+
+    #[test]
+    fn test_saturating_relation() {
+        let mut relation = Relation::new(SaturatingNum(1u8), |x| x * 3 + 1);
+
+        assert!(relation
+            .take(6)
+            .eq([1, 4, 13, 40, 121, 255].map(SaturatingNum)));
+
+        assert_eq!(relation.nth(10), SaturatingNum(u8::MAX));
+
+        let mut relation = Relation::new(SaturatingNum(100i8), |x| x - 50);
+
+        assert_eq!(relation.nth(10), SaturatingNum(i8::MIN));
+    }
+
+    #[test]
+    fn test_saturating_division() {
+        assert_eq!(SaturatingNum(i32::MIN) / -1, SaturatingNum(i32::MAX));
+
+        assert_eq!(
+            SaturatingNum(i32::MIN) % SaturatingNum(-1),
+            SaturatingNum(0)
+        );
+
+        assert_eq!(SaturatingNum(7u32) / 2, SaturatingNum(3));
+    }
+
+    #[test]
+    fn test_wrapping_relation() {
+        // An 8 bit hardware counter, which rolls over past 255
+        let mut relation = Relation::new(Wrapping(250u8), |x| x + Wrapping(3));
+
+        assert_eq!(relation.nth(2), Wrapping(0));
+
+        assert_eq!(relation.nth(1), Wrapping(3));
+    }
+}
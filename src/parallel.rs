@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+use rayon::prelude::*;
+
+use crate::{trace, ParamRelation, Relation};
+
+impl<I: Num + Clone + Send, F: FnOnce(I) -> I + Copy + Sync> Relation<I, F> {
+    /// Runs the rule of the relation for `steps` steps from each of `starters` across threads,
+    /// returning the final number reached from each starter, in the order of `starters`
+    pub fn par_batch(&self, starters: impl IntoParallelIterator<Item = I>, steps: usize) -> Vec<I>
+    where
+        I: Sync,
+    {
+        starters
+            .into_par_iter()
+            .map(|starter| Relation::new(starter, self.relation).nth(steps))
+            .collect()
+    }
+}
+
+impl<P: Clone + Send + Sync, I: Num + Clone + Send + Sync, F: FnOnce(P, I) -> I + Copy + Sync>
+    ParamRelation<P, I, F>
+{
+    /// Runs [`ParamRelation::sweep`] across threads, with each parameter swept on its own thread
+    pub fn par_sweep(
+        &self,
+        params: impl IntoParallelIterator<Item = P>,
+        steps: usize,
+    ) -> Vec<(P, I)> {
+        trace::span!("par_sweep", steps);
+
+        params
+            .into_par_iter()
+            .map(|param| {
+                trace::event!(TRACE, "sweeping parameter");
+
+                let last = self.clone().with_param(param.clone()).nth(steps);
+
+                (param, last)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "rand")]
+mod stochastic {
+    use num_traits::Float;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::{MonteCarlo, StochasticRelation};
+
+    impl<I, F, R> StochasticRelation<I, F, R>
+    where
+        I: Float + Send + Sync,
+        F: FnOnce(I, &mut R) -> I + Copy + Sync,
+        R: Rng + SeedableRng + Clone + Send + Sync,
+    {
+        /// Runs [`StochasticRelation::monte_carlo`] across threads
+        ///
+        /// The generators of the paths are seeded the same way, so the summary is identical to the single threaded one
+        pub fn par_monte_carlo(&self, paths: usize, steps: usize) -> MonteCarlo<I> {
+            let paths = self
+                .path_rngs(paths)
+                .into_par_iter()
+                .map(|rng| self.path(rng, steps))
+                .collect();
+
+            MonteCarlo::from_paths(paths)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_batch() {
+        let relation = Relation::new(0, |x| x * 2 + 1);
+
+        assert_eq!(relation.par_batch(0..4, 2), [3, 7, 11, 15]);
+
+        let starters: Vec<f64> = (1..=1000).map(f64::from).collect();
+        let roots = Relation::new(1., |x: f64| (x + 2. / x) / 2.).par_batch(starters, 50);
+
+        assert!(roots.iter().all(|root| (root - 2f64.sqrt()).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_par_sweep() {
+        let logistic = ParamRelation::new(0., 0.5, |r: f64, x: f64| r * x * (1. - x));
+        let params: Vec<f64> = (0..100).map(|i| 2.5 + f64::from(i) / 100.).collect();
+
+        assert_eq!(
+            logistic.par_sweep(params.clone(), 1000),
+            logistic.sweep(params, 1000)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_par_monte_carlo() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        use crate::finance::geometric_brownian_motion;
+
+        let prices = geometric_brownian_motion(100., 0.05, 0.2, 0.01, StdRng::seed_from_u64(4));
+
+        assert_eq!(
+            prices.par_monte_carlo(2000, 100),
+            prices.monte_carlo(2000, 100)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+#[cfg(feature = "alloc")]
+use crate::trace;
+
+/// A family of relations sharing a rule with a parameter, where `t(n+1) = f(p, t(n))`
+///
+/// Changing the parameter gives another member of the family, such as the growth rate `r` of the logistic map
+/// `t(n+1) = r * t(n) * (1 - t(n))`, and [`ParamRelation::sweep`] runs the family across many parameters
+#[derive(Debug, Copy, Clone)]
+pub struct ParamRelation<P, I, F> {
+    param: P,
+    starter: I,
+    current_number: I,
+    index: usize,
+    relation: F,
+}
+
+impl<P: Clone, I: Num + Clone, F: FnOnce(P, I) -> I + Copy> ParamRelation<P, I, F> {
+    /// Create a new [`ParamRelation`]
+    pub fn new(param: P, starter: I, relation: F) -> Self {
+        Self {
+            param,
+            starter: starter.clone(),
+            current_number: starter,
+            index: 0,
+            relation,
+        }
+    }
+
+    /// Returns the parameter
+    pub fn param(&self) -> &P {
+        &self.param
+    }
+
+    /// Returns the member of the family with `param`, rewound to the starter number
+    pub fn with_param(self, param: P) -> Self {
+        Self::new(param, self.starter, self.relation)
+    }
+
+    /// Returns the starter number, at index `0`
+    pub fn starter(&self) -> &I {
+        &self.starter
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        (self.relation)(self.param.clone(), self.current_number.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current_number = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`ParamRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_number.clone()
+    }
+
+    /// Runs the relation for `steps` steps from the starter number with each of `params`,
+    /// returning each parameter with the final number
+    #[cfg(feature = "alloc")]
+    pub fn sweep(&self, params: impl IntoIterator<Item = P>, steps: usize) -> Vec<(P, I)> {
+        trace::span!("sweep", steps);
+
+        params
+            .into_iter()
+            .map(|param| {
+                trace::event!(TRACE, "sweeping parameter");
+
+                let last = self.clone().with_param(param.clone()).nth(steps);
+
+                (param, last)
+            })
+            .collect()
+    }
+
+    /// Runs the relation for `steps` steps from the starter number with each of `params`,
+    /// returning each parameter with every number from the starter to the final number
+    #[cfg(feature = "alloc")]
+    pub fn sweep_trajectories(
+        &self,
+        params: impl IntoIterator<Item = P>,
+        steps: usize,
+    ) -> Vec<(P, Vec<I>)> {
+        trace::span!("sweep_trajectories", steps);
+
+        params
+            .into_iter()
+            .map(|param| {
+                trace::event!(TRACE, "sweeping parameter");
+
+                let trajectory = self
+                    .clone()
+                    .with_param(param.clone())
+                    .take(steps.saturating_add(1))
+                    .collect();
+
+                (param, trajectory)
+            })
+            .collect()
+    }
+
+    /// Generates the points of a bifurcation diagram, sampling the attractor of the relation with each of `params`
+    ///
+    /// For each parameter, the first `transient` steps from the starter number are discarded so the relation can settle,
+    /// and then the next `samples` numbers are returned as `(param, number)` pairs, ready to be plotted
+    #[cfg(feature = "alloc")]
+    pub fn bifurcation(
+        &self,
+        params: impl IntoIterator<Item = P>,
+        transient: usize,
+        samples: usize,
+    ) -> Vec<(P, I)> {
+        trace::span!("bifurcation", transient, samples);
+
+        let mut points = Vec::new();
+
+        for param in params {
+            trace::event!(TRACE, "sweeping parameter");
+
+            let mut relation = self.clone().with_param(param.clone());
+            relation.nth(transient);
+
+            points.extend(relation.take(samples).map(|number| (param.clone(), number)));
+        }
+
+        points
+    }
+}
+
+impl<P: Clone, I: Num + Clone, F: FnOnce(P, I) -> I + Copy> Iterator for ParamRelation<P, I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        ParamRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_param_relation() {
+        let mut savings = ParamRelation::new(2, 100, |rate, x| x * rate);
+
+        assert_eq!(savings.nth(3), 800);
+
+        assert_eq!(savings.param(), &2);
+
+        let mut tripled = savings.with_param(3);
+
+        assert_eq!(tripled.index(), 0);
+
+        assert_eq!(tripled.nth(3), 2700);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sweep() {
+        let growth = ParamRelation::new(0, 1, |rate, x| x * (1 + rate));
+
+        assert_eq!(growth.sweep([0, 1, 2], 3), [(0, 1), (1, 8), (2, 27)]);
+
+        assert_eq!(
+            growth.sweep_trajectories([1, 2], 2),
+            [(1, alloc::vec![1, 2, 4]), (2, alloc::vec![1, 3, 9])]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bifurcation() {
+        let logistic = ParamRelation::new(0., 0.5, |r: f64, x: f64| r * x * (1. - x));
+
+        let points = logistic.bifurcation([2.5, 3.2], 1000, 4);
+
+        assert_eq!(points.len(), 8);
+
+        // Below 3 the attractor is the single fixed point 1 - 1/r
+        assert!(points[..4]
+            .iter()
+            .all(|&(r, x)| r == 2.5 && (x - 0.6).abs() < 1e-9));
+
+        // Just above 3 it is a cycle between two points
+        let cycle = &points[4..];
+
+        assert!((cycle[0].1 - cycle[2].1).abs() < 1e-9);
+
+        assert!((cycle[0].1 - cycle[1].1).abs() > 0.1);
+    }
+}
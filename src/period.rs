@@ -0,0 +1,175 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::{linear::reduce_mod, Cycle, LinearRecurrence};
+
+/// The terms of a [`LinearRecurrence`] modulo some number, precomputed up to the point they repeat
+///
+/// This generalises the Pisano period of the Fibonacci sequence.
+/// Once found, any term can be looked up in constant time, no matter how large its index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModularPeriod<I> {
+    modulus: I,
+    cycle: Cycle,
+    terms: Vec<I>,
+}
+
+impl<I: Clone> ModularPeriod<I> {
+    /// Returns the modulus the terms were reduced by
+    pub fn modulus(&self) -> &I {
+        &self.modulus
+    }
+
+    /// Returns the number of terms before the repetition begins, and the length of the period
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// Returns the length of the period
+    pub fn length(&self) -> usize {
+        self.cycle.length
+    }
+
+    /// Returns the term at `index` steps after the current number of the recurrence, modulo the modulus
+    pub fn term(&self, index: u128) -> I {
+        let Cycle { tail, length } = self.cycle;
+
+        let position = match usize::try_from(index) {
+            Ok(index) if index < tail => index,
+            _ => tail + ((index - tail as u128) % length as u128) as usize,
+        };
+
+        self.terms[position].clone()
+    }
+}
+
+/// Advances a window of terms by one step, modulo `modulus`
+fn step<I: Num + Clone + PartialOrd>(coefficients: &[I], window: &mut Vec<I>, modulus: &I) {
+    let following = coefficients
+        .iter()
+        .zip(window.iter().rev())
+        .fold(I::zero(), |sum, (coefficient, term)| {
+            reduce_mod(sum + coefficient.clone() * term.clone(), modulus)
+        });
+
+    window.remove(0);
+    window.push(following);
+}
+
+impl<I: Num + Clone + PartialOrd> LinearRecurrence<I> {
+    /// Finds the period of the recurrence modulo `modulus`, from the current number onwards
+    ///
+    /// Uses Brent's algorithm on the window of the last `k` terms, and then stores one period of terms.
+    /// Returns [`None`] if the terms do not repeat within `max_steps` steps
+    ///
+    /// # Panics
+    /// - If `modulus` is not positive
+    pub fn period_mod(&self, modulus: I, max_steps: usize) -> Option<ModularPeriod<I>> {
+        assert!(modulus > I::zero(), "the modulus must be positive");
+
+        let coefficients: Vec<I> = self
+            .coefficients()
+            .iter()
+            .map(|c| reduce_mod(c.clone(), &modulus))
+            .collect();
+        let start: Vec<I> = self
+            .terms()
+            .iter()
+            .map(|t| reduce_mod(t.clone(), &modulus))
+            .collect();
+
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        step(&coefficients, &mut hare, &modulus);
+        let mut steps = 1;
+
+        while tortoise != hare {
+            if steps >= max_steps {
+                return None;
+            }
+
+            if power == length {
+                tortoise.clone_from(&hare);
+                power *= 2;
+                length = 0;
+            }
+
+            step(&coefficients, &mut hare, &modulus);
+            length += 1;
+            steps += 1;
+        }
+
+        let mut tortoise = start.clone();
+        let mut hare = start;
+        for _ in 0..length {
+            step(&coefficients, &mut hare, &modulus);
+        }
+
+        let mut terms = Vec::new();
+        while tortoise != hare {
+            terms.push(tortoise[0].clone());
+            step(&coefficients, &mut tortoise, &modulus);
+            step(&coefficients, &mut hare, &modulus);
+        }
+
+        let tail = terms.len();
+        for _ in 0..length {
+            terms.push(tortoise[0].clone());
+            step(&coefficients, &mut tortoise, &modulus);
+        }
+
+        Some(ModularPeriod {
+            modulus,
+            cycle: Cycle { tail, length },
+            terms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pisano_period() {
+        let fibonacci = LinearRecurrence::new([1u64, 1], [0, 1]);
+
+        let periods: Vec<usize> = [2, 3, 5, 10, 1000]
+            .into_iter()
+            .map(|m| fibonacci.period_mod(m, 10_000).unwrap().length())
+            .collect();
+
+        assert_eq!(periods, [3, 8, 20, 60, 1500]);
+
+        assert_eq!(fibonacci.period_mod(10, 10_000).unwrap().cycle().tail, 0);
+    }
+
+    #[test]
+    fn test_huge_index() {
+        let fibonacci = LinearRecurrence::new([1u64, 1], [0, 1]);
+        let period = fibonacci.period_mod(1000, 10_000).unwrap();
+
+        assert_eq!(period.term(90), 2_880_067_194_370_816_120 % 1000);
+
+        let index = 10u128.pow(30) + 7;
+
+        assert_eq!(
+            period.term(index),
+            fibonacci.nth_mod((index % 1500) as usize, 1000)
+        );
+    }
+
+    #[test]
+    fn test_eventually_periodic() {
+        // t(n+1) = 2 t(n) modulo 12 is 1, 2, 4, 8, 4, 8, ...
+        let doubling = LinearRecurrence::new([2], [1]);
+        let period = doubling.period_mod(12, 100).unwrap();
+
+        assert_eq!(period.cycle(), Cycle { tail: 2, length: 2 });
+
+        assert!((0..8).map(|n| period.term(n)).eq([1, 2, 4, 8, 4, 8, 4, 8]));
+    }
+}
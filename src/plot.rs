@@ -0,0 +1,259 @@
+use std::{io, path::Path, string::ToString, vec, vec::Vec};
+
+use num_traits::{Float, Num, ToPrimitive};
+use plotters::{
+    coord::Shift,
+    prelude::{
+        BitMapBackend, ChartBuilder, Circle, DrawingArea, DrawingAreaErrorKind, DrawingBackend,
+        IntoDrawingArea, LineSeries, RGBColor, SVGBackend, BLACK, BLUE, RED, WHITE,
+    },
+};
+
+use crate::{ParamRelation, Relation};
+
+/// The width and height of each chart, in pixels
+const SIZE: (u32, u32) = (800, 600);
+
+/// The number of points the rule's curve is sampled at in a cobweb diagram
+const CURVE_SAMPLES: usize = 200;
+
+/// How a series of points is drawn
+#[derive(Clone, Copy)]
+enum Style {
+    Line(RGBColor),
+    Points(RGBColor),
+}
+
+/// A chart of one or more series of points, with axes fitted to all of them
+struct Chart {
+    caption: &'static str,
+    series: Vec<(Style, Vec<(f64, f64)>)>,
+}
+
+/// Converts a pair of numbers to a point, or [`None`] if either is not a finite `f64`
+fn point(x: impl ToPrimitive, y: impl ToPrimitive) -> Option<(f64, f64)> {
+    let point = (x.to_f64()?, y.to_f64()?);
+
+    (point.0.is_finite() && point.1.is_finite()).then_some(point)
+}
+
+/// Returns the range spanned by `values`, widened slightly so points do not sit on the edge of the chart
+fn span(values: impl Iterator<Item = f64>) -> core::ops::Range<f64> {
+    let (low, high) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), value| {
+        (low.min(value), high.max(value))
+    });
+
+    if low > high {
+        return 0.0..1.0;
+    }
+
+    let padding = if low == high { 1. } else { (high - low) * 0.05 };
+
+    (low - padding)..(high + padding)
+}
+
+impl Chart {
+    fn points(&self) -> impl Iterator<Item = &(f64, f64)> {
+        self.series.iter().flat_map(|(_, points)| points)
+    }
+
+    /// Draws the chart onto `root`
+    fn draw<DB: DrawingBackend>(
+        &self,
+        root: DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(self.caption, ("sans-serif", 24))
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(50)
+            .build_cartesian_2d(
+                span(self.points().map(|point| point.0)),
+                span(self.points().map(|point| point.1)),
+            )?;
+
+        chart.configure_mesh().draw()?;
+
+        for (style, points) in &self.series {
+            match *style {
+                Style::Line(color) => {
+                    chart.draw_series(LineSeries::new(points.iter().copied(), color))?;
+                }
+                Style::Points(color) => {
+                    chart.draw_series(points.iter().map(|&point| Circle::new(point, 1, color)))?;
+                }
+            }
+        }
+
+        root.present()
+    }
+
+    /// Renders the chart to `path`, as an SVG if the path ends in `.svg`, and as a bitmap such as a PNG otherwise
+    fn render(&self, path: &Path) -> io::Result<()> {
+        let is_svg = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("svg"));
+
+        let result = if is_svg {
+            self.draw(SVGBackend::new(path, SIZE).into_drawing_area())
+                .map_err(|error| error.to_string())
+        } else {
+            self.draw(BitMapBackend::new(path, SIZE).into_drawing_area())
+                .map_err(|error| error.to_string())
+        };
+
+        result.map_err(io::Error::other)
+    }
+}
+
+impl<I: Num + Clone + ToPrimitive, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Draws a line chart of the next `count` terms against their index, beginning with the current number,
+    /// without updating the struct
+    ///
+    /// The chart is written to `path`, as an SVG if it ends in `.svg`, and as a bitmap such as a PNG otherwise.
+    /// Terms which are not finite are left out
+    ///
+    /// # Errors
+    /// - If the chart cannot be drawn or written to `path`
+    pub fn plot(&self, path: impl AsRef<Path>, count: usize) -> io::Result<()> {
+        let points = (self.index..)
+            .zip(self.clone().take(count))
+            .filter_map(|(index, term)| point(index, term))
+            .collect();
+
+        Chart {
+            caption: "Terms",
+            series: vec![(Style::Line(BLUE), points)],
+        }
+        .render(path.as_ref())
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Draws the cobweb diagram of `steps` steps from the current number, over the curve of the rule
+    /// and the diagonal `y = x`, without updating the struct
+    ///
+    /// See [`Relation::cobweb`] for the points of the diagram, and [`Relation::plot`] for the formats written
+    ///
+    /// # Errors
+    /// - If the chart cannot be drawn or written to `path`
+    pub fn plot_cobweb(&self, path: impl AsRef<Path>, steps: usize) -> io::Result<()> {
+        let web: Vec<_> = self
+            .cobweb(steps)
+            .into_iter()
+            .filter_map(|(x, y)| point(x, y))
+            .collect();
+
+        let range = span(web.iter().map(|point| point.0));
+        let step = (range.end - range.start) / CURVE_SAMPLES as f64;
+
+        let curve = (0..=CURVE_SAMPLES)
+            .filter_map(|sample| {
+                let x = range.start + step * sample as f64;
+
+                point(x, (self.relation)(I::from(x)?))
+            })
+            .collect();
+
+        let diagonal = vec![(range.start, range.start), (range.end, range.end)];
+
+        Chart {
+            caption: "Cobweb diagram",
+            series: vec![
+                (Style::Line(BLACK), curve),
+                (Style::Line(RED), diagonal),
+                (Style::Line(BLUE), web),
+            ],
+        }
+        .render(path.as_ref())
+    }
+}
+
+impl<P: Clone + ToPrimitive, I: Num + Clone + ToPrimitive, F: FnOnce(P, I) -> I + Copy>
+    ParamRelation<P, I, F>
+{
+    /// Draws the bifurcation diagram from [`ParamRelation::bifurcation`], with each parameter against the numbers it settles to
+    ///
+    /// See [`Relation::plot`] for the formats written
+    ///
+    /// # Errors
+    /// - If the chart cannot be drawn or written to `path`
+    pub fn plot_bifurcation(
+        &self,
+        path: impl AsRef<Path>,
+        params: impl IntoIterator<Item = P>,
+        transient: usize,
+        samples: usize,
+    ) -> io::Result<()> {
+        let points = self
+            .bifurcation(params, transient, samples)
+            .into_iter()
+            .filter_map(|(param, number)| point(param, number))
+            .collect();
+
+        Chart {
+            caption: "Bifurcation diagram",
+            series: vec![(Style::Points(BLUE), points)],
+        }
+        .render(path.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, format, fs, path::PathBuf};
+
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("relation-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_plot_svg() {
+        let path = temp_path("terms.svg");
+
+        Relation::new(1., |x| x * 1.1).plot(&path, 30).unwrap();
+
+        let svg = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(svg.starts_with("<svg"));
+
+        assert!(svg.contains("<polyline"));
+    }
+
+    #[test]
+    fn test_plot_png() {
+        let path = temp_path("cobweb.png");
+
+        Relation::new(0.2, |x: f64| 3.2 * x * (1. - x))
+            .plot_cobweb(&path, 20)
+            .unwrap();
+
+        let png = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let path = temp_path("bifurcation.svg");
+        let logistic = ParamRelation::new(0., 0.5, |r: f64, x: f64| r * x * (1. - x));
+        let params = (0..100).map(|step| 2.5 + step as f64 * 0.015);
+
+        logistic.plot_bifurcation(&path, params, 200, 50).unwrap();
+
+        let svg = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(svg.matches("<circle").count() >= 5000);
+    }
+
+    #[test]
+    fn test_plot_error() {
+        let path = temp_path("missing").join("terms.png");
+
+        assert!(Relation::new(1, |x| x + 1).plot(path, 10).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::ToPrimitive;
+
+use crate::Relation;
+
+impl<T, F> Relation<Ratio<T>, F>
+where
+    T: Clone + Integer,
+    Ratio<T>: ToPrimitive,
+    F: FnOnce(Ratio<T>) -> Ratio<T> + Copy,
+{
+    /// Converts the current number to the nearest [`f64`]
+    ///
+    /// Returns [`None`] if the fraction cannot be represented as an [`f64`]
+    pub fn current_f64(&self) -> Option<f64> {
+        self.current_number.to_f64()
+    }
+
+    /// Converts the relation into an iterator over the terms, as [`f64`] approximations
+    ///
+    /// The terms are still calculated exactly, and are only converted as they are yielded
+    pub fn into_f64_terms(self) -> impl Iterator<Item = Option<f64>> {
+        self.map(|term| term.to_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_division() {
+        let mut relation = Relation::new(Ratio::new(1i64, 1), |x| x / 2);
+
+        assert_eq!(relation.calculate_next(), Ratio::new(1, 2));
+
+        assert_eq!(relation.nth(10), Ratio::new(1, 1024));
+
+        assert_eq!(relation.current_f64(), Some(1. / 1024.));
+    }
+
+    #[test]
+    fn test_f64_terms() {
+        let relation = Relation::new(Ratio::new(1i64, 3), |x| x * 3 - 1);
+
+        assert!(relation.into_f64_terms().take(4).eq([
+            Some(1. / 3.),
+            Some(0.),
+            Some(-1.),
+            Some(-4.)
+        ]));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_big_rational() {
+        use num_bigint::BigInt;
+        use num_rational::BigRational;
+
+        let mut relation = Relation::new(BigRational::from_integer(BigInt::from(1)), |x| {
+            x / BigInt::from(3)
+        });
+
+        assert_eq!(
+            relation.nth(100),
+            BigRational::new(BigInt::from(1), BigInt::from(3).pow(100))
+        );
+
+        let approximation = relation.current_f64().unwrap();
+
+        assert!((approximation * 3f64.powi(100) - 1.).abs() < 1e-12);
+    }
+}
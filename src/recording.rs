@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+
+use num_traits::Num;
+
+use crate::Relation;
+
+/// A relation which records every term it reaches, along with its index
+///
+/// Created by [`Relation::recording`]. The recorded terms are consecutive,
+/// beginning with the current number of the relation when recording began
+#[derive(Debug, Clone)]
+pub struct RecordingRelation<I, F> {
+    relation: Relation<I, F>,
+    history: Vec<I>,
+    first_index: usize,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Wraps the relation so that every term it reaches is recorded, beginning with the current number
+    pub fn recording(self) -> RecordingRelation<I, F> {
+        RecordingRelation {
+            history: Vec::from([self.current_number.clone()]),
+            first_index: self.index,
+            relation: self,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> RecordingRelation<I, F> {
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.relation.index()
+    }
+
+    /// Returns the recorded terms, from the first recorded term up to and including the current number
+    pub fn history(&self) -> &[I] {
+        &self.history
+    }
+
+    /// Returns the index of the first recorded term
+    pub fn first_index(&self) -> usize {
+        self.first_index
+    }
+
+    /// Returns the recorded term at `index`, or [`None`] if it has not been recorded
+    pub fn term(&self, index: usize) -> Option<&I> {
+        self.history.get(index.checked_sub(self.first_index)?)
+    }
+
+    /// Returns an iterator over the recorded terms along with their indexes
+    pub fn indexed_history(&self) -> impl Iterator<Item = (usize, &I)> + '_ {
+        (self.first_index..).zip(&self.history)
+    }
+
+    /// Calculates and returns the next number in the sequence, without recording it
+    pub fn calculate_next(&self) -> I {
+        self.relation.calculate_next()
+    }
+
+    /// Calculates the next number in the sequence, records it, and updates the struct
+    pub fn next(&mut self) {
+        self.relation.next();
+        self.history.push(self.relation.current_number.clone());
+    }
+
+    /// Calls [`RecordingRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        self.history.reserve(index);
+
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.relation.current_number.clone()
+    }
+
+    /// Rewinds the relation to the previous recorded term, forgetting the current number, and returns the new current number
+    ///
+    /// Returns [`None`] without changing the relation if the current number is the first recorded term
+    pub fn prev(&mut self) -> Option<&I> {
+        self.back(1)
+    }
+
+    /// Rewinds the relation by `steps` recorded terms, forgetting the terms after it, and returns the new current number
+    ///
+    /// Returns [`None`] without changing the relation if fewer than `steps` terms were recorded before the current number
+    pub fn back(&mut self, steps: usize) -> Option<&I> {
+        let remaining = self
+            .history
+            .len()
+            .checked_sub(steps)
+            .filter(|&len| len > 0)?;
+
+        self.history.truncate(remaining);
+        self.relation.current_number = self.history[remaining - 1].clone();
+        self.relation.index -= steps;
+
+        Some(&self.relation.current_number)
+    }
+
+    /// Stops recording, returning the relation and the recorded terms
+    pub fn into_parts(self) -> (Relation<I, F>, Vec<I>) {
+        (self.relation, self.history)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Iterator for RecordingRelation<I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation, recording the next number
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.relation.current_number.clone();
+        RecordingRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording() {
+        let mut recording = Relation::new(1, |x| x * 2).recording();
+
+        assert_eq!(recording.history(), [1]);
+
+        assert_eq!(recording.nth(4), 16);
+
+        assert_eq!(recording.history(), [1, 2, 4, 8, 16]);
+
+        assert_eq!(recording.term(3), Some(&8));
+
+        assert_eq!(recording.term(5), None);
+    }
+
+    #[test]
+    fn test_step_back() {
+        let mut recording = Relation::new(1, |x| x + 1).recording();
+
+        recording.nth(5);
+
+        assert_eq!(recording.prev(), Some(&5));
+
+        assert_eq!(recording.index(), 4);
+
+        assert_eq!(recording.back(3), Some(&2));
+
+        assert_eq!(recording.history(), [1, 2]);
+
+        assert_eq!(recording.back(2), None);
+
+        assert_eq!(recording.current(), &2);
+
+        // Stepping forwards again records the terms again
+        assert_eq!(recording.nth(2), 4);
+
+        assert_eq!(recording.history(), [1, 2, 3, 4]);
+
+        assert_eq!(recording.back(3), Some(&1));
+
+        assert_eq!(recording.prev(), None);
+    }
+
+    #[test]
+    fn test_recording_from_later_index() {
+        let mut relation = Relation::new(0, |x| x + 3);
+
+        relation.nth(10);
+
+        let mut recording = relation.recording();
+
+        recording.by_ref().take(2).for_each(drop);
+
+        assert_eq!(recording.first_index(), 10);
+
+        assert_eq!(recording.term(9), None);
+
+        assert!(recording
+            .indexed_history()
+            .eq([(10, &30), (11, &33), (12, &36)]));
+
+        let (relation, history) = recording.into_parts();
+
+        assert_eq!(relation.index(), 12);
+
+        assert_eq!(history.len(), 3);
+    }
+}
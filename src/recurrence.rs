@@ -0,0 +1,286 @@
+use num_traits::Num;
+
+#[cfg(feature = "alloc")]
+use crate::LinearRecurrence;
+
+/// A linear recurrence of fixed order `K`, where `t(n+K) = c1 * t(n+K-1) + c2 * t(n+K-2) + ... + cK * t(n)`
+///
+/// Unlike [`LinearRecurrence`](crate::LinearRecurrence), the coefficients and terms are stored in arrays,
+/// so it needs no allocation and works without the `alloc` feature
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "I: serde::Serialize",
+        deserialize = "I: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Recurrence<I, const K: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::array"))]
+    coefficients: [I; K],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::array"))]
+    terms: [I; K],
+    index: usize,
+}
+
+/// Calculates the number after the last of `terms`, from `coefficients` ordered from the one which multiplies the newest term
+///
+/// Shared by the linear recurrences, whether their terms are stored in arrays or vectors
+pub(crate) fn calculate_following<I: Num + Clone>(coefficients: &[I], terms: &[I]) -> I {
+    coefficients
+        .iter()
+        .zip(terms.iter().rev())
+        .fold(I::zero(), |sum, (coefficient, term)| {
+            sum + coefficient.clone() * term.clone()
+        })
+}
+
+/// Drops the oldest of `terms`, moving the rest up a place, and puts `following` after them
+pub(crate) fn shift_window<I>(terms: &mut [I], following: I) {
+    terms.rotate_left(1);
+
+    if let Some(newest) = terms.last_mut() {
+        *newest = following;
+    }
+}
+
+impl<I: Num + Clone, const K: usize> Recurrence<I, K> {
+    /// Create a new [`Recurrence`]
+    ///
+    /// The coefficients are ordered from `c1`, which multiplies the newest term, to `cK`, which multiplies the oldest.
+    /// The initial terms are ordered from `t(0)` to `t(K-1)`
+    ///
+    /// # Panics
+    /// - If `K` is zero, as there would be no current number
+    pub const fn new(coefficients: [I; K], initial: [I; K]) -> Self {
+        assert!(K > 0, "a recurrence requires at least one coefficient");
+
+        Self {
+            coefficients,
+            terms: initial,
+            index: 0,
+        }
+    }
+
+    /// Returns the order `K` of the recurrence
+    pub const fn order(&self) -> usize {
+        K
+    }
+
+    /// Returns the coefficients, ordered from `c1` to `cK`
+    pub const fn coefficients(&self) -> &[I; K] {
+        &self.coefficients
+    }
+
+    /// Returns the current number
+    pub const fn current(&self) -> &I {
+        &self.terms[0]
+    }
+
+    /// Returns the current number, followed by the `K - 1` numbers after it
+    pub const fn terms(&self) -> &[I; K] {
+        &self.terms
+    }
+
+    /// Returns the index of the current number, where the first initial term is at index `0`
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence
+    pub fn calculate_next(&self) -> I {
+        match self.terms.get(1) {
+            Some(next) => next.clone(),
+            None => calculate_following(&self.coefficients, &self.terms),
+        }
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        let following = calculate_following(&self.coefficients, &self.terms);
+
+        shift_window(&mut self.terms, following);
+        self.index += 1;
+    }
+
+    /// Calls [`Recurrence::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.terms[0].clone()
+    }
+}
+
+macro_rules! impl_const_recurrence {
+    ($($int:ty),*) => {
+        $(
+            impl<const K: usize> Recurrence<$int, K> {
+                /// Calculates and returns the number `index` steps after the current number, without updating the struct
+                ///
+                /// Unlike [`Recurrence::nth`] this is a `const fn`, so lookup tables of terms can be built at compile time.
+                /// Only the terms up to the one returned are calculated, and an overflow is a compile error in a `const` context
+                pub const fn nth_const(&self, index: usize) -> $int {
+                    if index < K {
+                        return self.terms[index];
+                    }
+
+                    let mut terms = self.terms;
+                    let mut step = 0;
+
+                    while step <= index - K {
+                        let mut following = 0;
+                        let mut position = 0;
+
+                        while position < K {
+                            following += self.coefficients[position] * terms[K - 1 - position];
+                            position += 1;
+                        }
+
+                        let mut position = 1;
+
+                        while position < K {
+                            terms[position - 1] = terms[position];
+                            position += 1;
+                        }
+
+                        terms[K - 1] = following;
+                        step += 1;
+                    }
+
+                    terms[K - 1]
+                }
+            }
+        )*
+    };
+}
+
+impl_const_recurrence!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<I: Num + Clone, const K: usize> Iterator for Recurrence<I, K> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.terms[0].clone();
+        Recurrence::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Num + Clone, const K: usize> From<Recurrence<I, K>> for LinearRecurrence<I> {
+    fn from(recurrence: Recurrence<I, K>) -> Self {
+        LinearRecurrence::from_parts(
+            recurrence.coefficients.into(),
+            recurrence.terms.into(),
+            recurrence.index,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Num + Clone, const K: usize> TryFrom<LinearRecurrence<I>> for Recurrence<I, K> {
+    type Error = LinearRecurrence<I>;
+
+    /// Converts a [`LinearRecurrence`] of order `K`, keeping its current terms and index
+    ///
+    /// # Errors
+    /// - If the order of the recurrence is not `K`, in which case it is returned unchanged
+    fn try_from(recurrence: LinearRecurrence<I>) -> Result<Self, Self::Error> {
+        let (Ok(coefficients), Ok(terms)) = (
+            <&[I; K]>::try_from(recurrence.coefficients()),
+            <&[I; K]>::try_from(recurrence.terms()),
+        ) else {
+            return Err(recurrence);
+        };
+
+        Ok(Self {
+            coefficients: coefficients.clone(),
+            terms: terms.clone(),
+            index: recurrence.index(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence() {
+        let mut fibonacci = Recurrence::new([1u64, 1], [0, 1]);
+
+        assert_eq!(fibonacci.calculate_next(), 1);
+
+        assert_eq!(fibonacci.nth(10), 55);
+
+        assert_eq!(fibonacci.terms(), &[55, 89]);
+
+        assert_eq!(fibonacci.index(), 10);
+
+        let tribonacci = Recurrence::new([1, 1, 1], [0, 0, 1]);
+
+        assert!(tribonacci.take(8).eq([0, 0, 1, 1, 2, 4, 7, 13]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_linear_recurrence_conversion() {
+        let mut pell = Recurrence::new([2i64, 1], [0, 1]);
+        pell.nth(5);
+
+        let linear = LinearRecurrence::from(pell);
+
+        assert_eq!(linear.index(), 5);
+
+        assert_eq!(linear.nth_calculated(3), pell.nth(3));
+
+        let back = Recurrence::<i64, 2>::try_from(linear.clone()).unwrap();
+
+        assert_eq!(back.terms(), &[29, 70]);
+
+        assert_eq!(Recurrence::<i64, 3>::try_from(linear.clone()), Err(linear));
+    }
+
+    #[test]
+    fn test_nth_const() {
+        const FIBONACCI: Recurrence<u64, 2> = Recurrence::new([1, 1], [0, 1]);
+        const F93: u64 = FIBONACCI.nth_const(93);
+
+        assert_eq!(F93, 12_200_160_415_121_876_738);
+
+        assert_eq!(FIBONACCI.nth_const(1), 1);
+
+        let tribonacci = Recurrence::new([1u32, 1, 1], [0, 0, 1]);
+
+        assert!((0..10)
+            .map(|index| tribonacci.nth_const(index))
+            .eq(tribonacci.take(10)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_recurrence_round_trip() {
+        let mut fibonacci = Recurrence::new([1u64, 1], [0, 1]);
+        fibonacci.nth(3);
+
+        let json = serde_json::to_string(&fibonacci).unwrap();
+
+        assert_eq!(json, r#"{"coefficients":[1,1],"terms":[2,3],"index":3}"#);
+
+        let resumed: Recurrence<u64, 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(resumed, fibonacci);
+
+        assert!(serde_json::from_str::<Recurrence<u64, 3>>(&json).is_err());
+
+        assert!(serde_json::from_str::<Recurrence<u64, 1>>(&json).is_err());
+    }
+}
@@ -0,0 +1,334 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io;
+
+use num_traits::{Float, Num};
+
+#[cfg(feature = "alloc")]
+use crate::Table;
+use crate::{abs_diff, trace, Convergence, Error, Stats};
+
+/// The interface shared by the relation types, which step a current number forward one term at a time
+///
+/// Convergence, statistics, tables, and exports are written once against this trait, and are available
+/// on every relation which implements it once the trait is in scope. [`Relation`](crate::Relation) and
+/// [`ExpressionRelation`](crate::ExpressionRelation) keep inherent methods of the same names, which call these
+pub trait RelationLike<I> {
+    /// Returns the current number
+    fn current(&self) -> &I;
+
+    /// Returns the index of the current number, where the first term is at index `0`
+    fn index(&self) -> usize;
+
+    /// Calculates and returns the next number in the sequence, without updating the struct
+    fn calculate_next(&self) -> I;
+
+    /// Calculates the next number in the sequence and updates the struct
+    fn advance(&mut self);
+
+    /// Calls [`RelationLike::advance`] `steps` times, and then returns the current number
+    fn step_forward(&mut self, steps: usize) -> I
+    where
+        I: Clone,
+    {
+        for _ in 0..steps {
+            self.advance();
+        }
+
+        self.current().clone()
+    }
+
+    /// Returns `count` terms, beginning with the current number, without updating the struct
+    #[cfg(feature = "alloc")]
+    fn collect_terms(&self, count: usize) -> Vec<I>
+    where
+        Self: Clone,
+        I: Clone,
+    {
+        let mut relation = self.clone();
+        // The count may be far more than fits in memory, so only a little is reserved up front
+        let mut terms = Vec::with_capacity(count.min(1024));
+
+        for position in 0..count {
+            terms.push(relation.current().clone());
+
+            if position + 1 < count {
+                relation.advance();
+            }
+        }
+
+        terms
+    }
+
+    /// Advances until successive terms differ by less than `tolerance`
+    ///
+    /// Returns the last term as the estimated limit, along with the number of steps taken
+    ///
+    /// # Errors
+    /// - If the terms are still not within `tolerance` of each other after `max_steps` steps
+    fn converge(&mut self, tolerance: I, max_steps: usize) -> Result<Convergence<I>, Error>
+    where
+        I: Num + Clone + PartialOrd,
+    {
+        trace::span!("converge", start_index = self.index(), max_steps);
+
+        for steps in 1..=max_steps {
+            let previous = self.current().clone();
+            self.advance();
+
+            if abs_diff(self.current(), &previous) < tolerance {
+                trace::event!(DEBUG, steps, "converged");
+
+                return Ok(Convergence {
+                    limit: self.current().clone(),
+                    steps,
+                });
+            }
+        }
+
+        trace::event!(WARN, steps = max_steps, "did not converge");
+
+        Err(Error::DidNotConverge { steps: max_steps })
+    }
+
+    /// Calculates summary statistics over `count` terms, beginning with the current number, without updating the struct
+    ///
+    /// The terms are not stored, so this runs in constant memory however many terms there are.
+    /// Returns [`None`] if `count` is zero
+    fn stats(&self, count: usize) -> Option<Stats<I>>
+    where
+        Self: Clone,
+        I: Float,
+    {
+        let mut relation = self.clone();
+        let mut stats = (count > 0).then(|| Stats::from_term(*relation.current()))?;
+
+        for _ in 1..count {
+            relation.advance();
+            stats.push(*relation.current());
+        }
+
+        Some(stats)
+    }
+
+    /// Creates a [`Table`] of the next `count` terms, beginning with the current number, without updating the struct
+    #[cfg(feature = "alloc")]
+    fn table(&self, count: usize) -> Table<I>
+    where
+        Self: Clone,
+        I: Clone,
+    {
+        Table::new(self.index(), self.collect_terms(count))
+    }
+
+    /// Writes the index and value of the next `count` terms as comma separated values, beginning with the current number,
+    /// without updating the struct
+    ///
+    /// The first line is the header `index,value`, so the output can be opened directly as a spreadsheet
+    ///
+    /// # Errors
+    /// - If writing to `writer` fails
+    #[cfg(feature = "std")]
+    fn export_csv(&self, writer: impl io::Write, count: usize) -> io::Result<()>
+    where
+        Self: Clone,
+        I: Clone + core::fmt::Display,
+    {
+        crate::csv::write_terms(writer, "value", self.index(), Terms::new(self, count))
+    }
+
+    /// Writes the next `count` terms as JSON Lines, beginning with the current number, without updating the struct
+    ///
+    /// Each line is an object such as `{"index":0,"value":1.5}`, written as the term is calculated
+    ///
+    /// # Errors
+    /// - If a term cannot be serialized, or writing to `writer` fails
+    #[cfg(feature = "json")]
+    fn export_json_lines(&self, writer: impl io::Write, count: usize) -> io::Result<()>
+    where
+        Self: Clone,
+        I: Clone + serde::Serialize,
+    {
+        crate::json::write_terms(writer, self.index(), Terms::new(self, count))
+    }
+}
+
+/// An iterator over `count` terms of a clone of a relation, which is only advanced between terms
+#[cfg(feature = "std")]
+struct Terms<R, I> {
+    relation: R,
+    remaining: usize,
+    term: PhantomData<fn() -> I>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Clone, I> Terms<R, I> {
+    fn new(relation: &R, count: usize) -> Self {
+        Self {
+            relation: relation.clone(),
+            remaining: count,
+            term: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Clone, R: RelationLike<I>> Iterator for Terms<R, I> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+
+        let term = self.relation.current().clone();
+
+        if self.remaining > 0 {
+            self.relation.advance();
+        }
+
+        Some(term)
+    }
+}
+
+/// Implements [`RelationLike`] by calling the inherent methods of the same names, with `next` as `advance`
+macro_rules! impl_relation_like {
+    ($(impl[$($generic:tt)*] for $relation:ty;)*) => {
+        $(
+            impl<$($generic)*> RelationLike<I> for $relation {
+                fn current(&self) -> &I {
+                    <$relation>::current(self)
+                }
+
+                fn index(&self) -> usize {
+                    <$relation>::index(self)
+                }
+
+                fn calculate_next(&self) -> I {
+                    <$relation>::calculate_next(self)
+                }
+
+                fn advance(&mut self) {
+                    <$relation>::next(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_relation_like! {
+    impl[I: Num + Clone, F: FnOnce(I) -> I + Copy] for crate::Relation<I, F>;
+    impl[I: Num + Clone] for crate::AffineRelation<I>;
+    impl[I: Num + Clone, F: FnOnce(usize, I) -> I + Copy] for crate::IndexedRelation<I, F>;
+    impl[I: Num + Clone, F: FnOnce([I; K]) -> I + Copy, const K: usize] for crate::HigherOrderRelation<I, F, K>;
+    impl[I: Num + Clone, const K: usize] for crate::Recurrence<I, K>;
+    impl[I: Num + Clone, F: FnOnce(I) -> I + Copy, H: FnMut(usize, &I)] for crate::ObservedRelation<I, F, H>;
+}
+
+#[cfg(feature = "alloc")]
+impl_relation_like! {
+    impl[I: Num + Clone] for crate::LinearRecurrence<I>;
+    impl[I: Clone] for crate::DynRelation<I>;
+}
+
+#[cfg(feature = "parse")]
+impl_relation_like! {
+    impl[I: Float] for crate::ExpressionRelation<I>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AffineRelation, HigherOrderRelation, Recurrence, Relation};
+
+    /// Returns the limit of any relation, to check the trait works generically
+    fn limit<R: RelationLike<f64>>(mut relation: R) -> f64 {
+        relation.converge(1e-12, 1000).unwrap().limit
+    }
+
+    #[test]
+    fn test_generic_convergence() {
+        assert!((limit(Relation::new(1., |x: f64| x / 2. + 1.)) - 2.).abs() < 1e-9);
+
+        assert!((limit(AffineRelation::new(1., 0.5, 1.)) - 2.).abs() < 1e-9);
+
+        // Repeatedly averaging the last two terms settles two thirds of the way from the first to the second
+        let averages = HigherOrderRelation::new([0., 1.], |[a, b]: [f64; 2]| (a + b) / 2.);
+
+        assert!((limit(averages) - 2. / 3.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_provided_methods() {
+        let mut recurrence = Recurrence::new([1u64, 1], [0, 1]);
+
+        assert_eq!(recurrence.step_forward(10), 55);
+
+        assert_eq!(RelationLike::index(&recurrence), 10);
+
+        let stats = RelationLike::stats(&AffineRelation::new(1., 1., 1.), 5).unwrap();
+
+        assert_eq!(stats.mean(), 3.);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_collect_terms() {
+        let recurrence = Recurrence::new([1u64, 1], [0, 1]);
+
+        assert_eq!(
+            RelationLike::collect_terms(&recurrence, 6),
+            [0, 1, 1, 2, 3, 5]
+        );
+
+        assert_eq!(
+            RelationLike::table(&HigherOrderRelation::new([0, 1], |[a, b]| a + b), 3).terms(),
+            [1, 1, 2]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_index_labels() {
+        use alloc::string::ToString;
+
+        let higher_order = HigherOrderRelation::new([0, 1], |[a, b]| a + b);
+        let mut recurrence = Recurrence::new([1, 1], [0, 1]);
+        recurrence.next();
+
+        // Both begin at the term 1, which is t(1)
+        assert_eq!(
+            RelationLike::table(&higher_order, 3),
+            RelationLike::table(&recurrence, 3)
+        );
+
+        assert_eq!(
+            RelationLike::table(&higher_order, 2).to_string(),
+            "n | t(n)\n\
+             --+-----\n\
+             1 |    1\n\
+             2 |    1\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_export_index_labels() {
+        use std::string::String;
+
+        let mut output = Vec::new();
+
+        RelationLike::export_csv(
+            &HigherOrderRelation::new([0, 1], |[a, b]| a + b),
+            &mut output,
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "index,value\n1,1\n2,1\n3,2\n"
+        );
+    }
+}
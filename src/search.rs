@@ -0,0 +1,111 @@
+use num_traits::Num;
+
+use crate::{Error, Relation};
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calls [`Relation::next`] until `predicate` holds for the current number
+    ///
+    /// Returns the index and value of the term where it stopped.
+    /// If `predicate` already holds for the current number, the relation is not advanced
+    ///
+    /// # Errors
+    /// - If `max_steps` is given, and `predicate` still does not hold after that many steps
+    pub fn advance_until(
+        &mut self,
+        mut predicate: impl FnMut(&I) -> bool,
+        max_steps: Option<usize>,
+    ) -> Result<(usize, I), Error> {
+        let mut steps = 0;
+
+        while !predicate(&self.current_number) {
+            if max_steps.is_some_and(|max_steps| steps >= max_steps) {
+                return Err(Error::StepLimitReached { steps });
+            }
+
+            self.next();
+            steps += 1;
+        }
+
+        Ok((self.index, self.current_number.clone()))
+    }
+
+    /// Finds the first term, from the current number onwards, for which `predicate` holds, without updating the struct
+    ///
+    /// Returns the index and value of that term, or [`None`] if it is not found within `max_steps` steps.
+    /// This shadows [`Iterator::find`], which can still be called as `Iterator::find(&mut relation, predicate)`
+    pub fn find(
+        &self,
+        mut predicate: impl FnMut(&I) -> bool,
+        max_steps: usize,
+    ) -> Option<(usize, I)> {
+        let mut number = self.current_number.clone();
+
+        for steps in 0..=max_steps {
+            if predicate(&number) {
+                return Some((self.index + steps, number));
+            }
+
+            if steps < max_steps {
+                number = (self.relation)(number);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_until() {
+        // How many years until $1000 at 5% interest exceeds $2000
+        let mut relation = Relation::new(1000., |x| x * 1.05);
+
+        let (years, balance) = relation.advance_until(|&x| x > 2000., None).unwrap();
+
+        assert_eq!(years, 15);
+
+        assert!(balance > 2000. && balance < 2100.);
+
+        assert_eq!(relation.index(), 15);
+
+        assert_eq!(
+            relation.advance_until(|&x| x > 2000., Some(0)),
+            Ok((15, balance))
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let collatz = |x: u64| {
+            if x.is_multiple_of(2) {
+                x / 2
+            } else {
+                3 * x + 1
+            }
+        };
+        let relation = Relation::new(27, collatz);
+
+        assert_eq!(relation.find(|&x| x == 1, 1000), Some((111, 1)));
+
+        assert_eq!(relation.find(|&x| x == 1, 110), None);
+
+        assert_eq!(relation.find(|&x| x == 27, 0), Some((0, 27)));
+
+        assert_eq!(relation.index(), 0);
+    }
+
+    #[test]
+    fn test_advance_until_step_limit() {
+        let mut relation = Relation::new(1, |x| x + 1);
+
+        assert_eq!(
+            relation.advance_until(|&x| x > 100, Some(10)),
+            Err(Error::StepLimitReached { steps: 10 })
+        );
+
+        assert_eq!(relation.current(), &11);
+    }
+}
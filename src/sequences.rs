@@ -0,0 +1,182 @@
+//! Ready-made relations for classic integer sequences
+//!
+//! Each sequence begins at its term at index `0`, and works with any number type able to hold its terms,
+//! such as [`u64`], or a big integer for terms beyond its range
+
+use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Num};
+
+#[cfg(feature = "alloc")]
+use crate::LinearRecurrence;
+use crate::{CheckedRelation, Error, IndexedRelation};
+
+/// Converts an index into a term, for the sequences built from their index
+///
+/// # Panics
+/// - If the index cannot be represented by the number type
+fn from_index<I: FromPrimitive>(index: usize) -> I {
+    I::from_usize(index).expect("the index should fit in the number type")
+}
+
+/// Create the Fibonacci sequence `0, 1, 1, 2, 3, 5, ...`, where `F(n+2) = F(n+1) + F(n)`
+#[cfg(feature = "alloc")]
+pub fn fibonacci<I: Num + Clone>() -> LinearRecurrence<I> {
+    LinearRecurrence::new([I::one(), I::one()], [I::zero(), I::one()])
+}
+
+/// Create the Lucas sequence `2, 1, 3, 4, 7, 11, ...`, which follows the Fibonacci recurrence from `2, 1`
+#[cfg(feature = "alloc")]
+pub fn lucas<I: Num + Clone>() -> LinearRecurrence<I> {
+    LinearRecurrence::new([I::one(), I::one()], [I::one() + I::one(), I::one()])
+}
+
+/// Create the Pell sequence `0, 1, 2, 5, 12, 29, ...`, where `P(n+2) = 2P(n+1) + P(n)`
+#[cfg(feature = "alloc")]
+pub fn pell<I: Num + Clone>() -> LinearRecurrence<I> {
+    LinearRecurrence::new([I::one() + I::one(), I::one()], [I::zero(), I::one()])
+}
+
+/// Create the tribonacci sequence `0, 0, 1, 1, 2, 4, 7, ...`, where each term is the sum of the three before it
+#[cfg(feature = "alloc")]
+pub fn tribonacci<I: Num + Clone>() -> LinearRecurrence<I> {
+    LinearRecurrence::new(
+        [I::one(), I::one(), I::one()],
+        [I::zero(), I::zero(), I::one()],
+    )
+}
+
+/// Create the factorials `1, 1, 2, 6, 24, ...`, where `n! = n * (n-1)!`
+///
+/// # Panics
+/// - When advancing to an index which cannot be represented by the number type
+pub fn factorial<I: Num + Clone + FromPrimitive>(
+) -> IndexedRelation<I, impl FnOnce(usize, I) -> I + Copy> {
+    IndexedRelation::new(I::one(), |n, x: I| x * from_index(n + 1))
+}
+
+/// Create the triangular numbers `0, 1, 3, 6, 10, ...`, where `T(n) = T(n-1) + n`
+///
+/// # Panics
+/// - When advancing to an index which cannot be represented by the number type
+pub fn triangular<I: Num + Clone + FromPrimitive>(
+) -> IndexedRelation<I, impl FnOnce(usize, I) -> I + Copy> {
+    IndexedRelation::new(I::zero(), |n, x: I| x + from_index(n + 1))
+}
+
+/// Create the Collatz sequence from `n`, where an even term is halved, and an odd term `t` is followed by `3t + 1`
+///
+/// The arithmetic is checked, so a term which would overflow the number type ends the sequence with [`Error::Overflow`]
+pub fn collatz<I: Num + Clone + CheckedAdd + CheckedMul>(
+    n: I,
+) -> CheckedRelation<I, impl FnOnce(I) -> Option<I> + Copy> {
+    CheckedRelation::new(n, |x: I| {
+        let two = I::one() + I::one();
+
+        if (x.clone() % two.clone()).is_zero() {
+            Some(x / two)
+        } else {
+            x.checked_mul(&(two + I::one()))?.checked_add(&I::one())
+        }
+    })
+}
+
+/// Runs the Collatz sequence from `n` until it reaches `1`, returning the number of steps and the largest term reached
+fn run_collatz<I: Num + Clone + CheckedAdd + CheckedMul + PartialOrd>(
+    n: I,
+    max_steps: usize,
+) -> Result<(usize, I), Error> {
+    let mut sequence = collatz(n.clone());
+    let mut largest = n;
+
+    while !sequence.current().is_one() {
+        if sequence.index() >= max_steps {
+            return Err(Error::StepLimitReached { steps: max_steps });
+        }
+
+        sequence.next()?;
+
+        if sequence.current() > &largest {
+            largest = sequence.current().clone();
+        }
+    }
+
+    Ok((sequence.index(), largest))
+}
+
+/// Returns the total stopping time of the Collatz sequence from `n`, which is the number of steps it takes to reach `1`
+///
+/// # Errors
+/// - If the sequence does not reach `1` within `max_steps` steps, such as from `0`, which never does
+/// - If a term overflows the number type
+pub fn stopping_time<I: Num + Clone + CheckedAdd + CheckedMul + PartialOrd>(
+    n: I,
+    max_steps: usize,
+) -> Result<usize, Error> {
+    run_collatz(n, max_steps).map(|(steps, _)| steps)
+}
+
+/// Returns the largest term the Collatz sequence from `n` reaches on its way to `1`
+///
+/// # Errors
+/// - If the sequence does not reach `1` within `max_steps` steps, such as from `0`, which never does
+/// - If a term overflows the number type
+pub fn max_excursion<I: Num + Clone + CheckedAdd + CheckedMul + PartialOrd>(
+    n: I,
+    max_steps: usize,
+) -> Result<I, Error> {
+    run_collatz(n, max_steps).map(|(_, largest)| largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_linear_sequences() {
+        assert!(fibonacci::<u64>()
+            .take(10)
+            .eq([0, 1, 1, 2, 3, 5, 8, 13, 21, 34]));
+
+        assert_eq!(fibonacci::<u64>().nth(90), 2_880_067_194_370_816_120);
+
+        assert!(lucas::<u64>().take(8).eq([2, 1, 3, 4, 7, 11, 18, 29]));
+
+        assert!(pell::<u64>().take(8).eq([0, 1, 2, 5, 12, 29, 70, 169]));
+
+        assert!(tribonacci::<u64>()
+            .take(10)
+            .eq([0, 0, 1, 1, 2, 4, 7, 13, 24, 44]));
+    }
+
+    #[test]
+    fn test_indexed_sequences() {
+        assert!(factorial::<u64>().take(6).eq([1, 1, 2, 6, 24, 120]));
+
+        assert_eq!(factorial::<u64>().nth(20), 2_432_902_008_176_640_000);
+
+        assert!(triangular::<u32>().take(6).eq([0, 1, 3, 6, 10, 15]));
+
+        assert_eq!(triangular::<u64>().nth(100), 5050);
+    }
+
+    #[test]
+    fn test_collatz() {
+        assert!(collatz(6u32).take(10).eq([6, 3, 10, 5, 16, 8, 4, 2, 1, 4]));
+
+        assert_eq!(stopping_time(27u64, 1000), Ok(111));
+
+        assert_eq!(max_excursion(27u64, 1000), Ok(9232));
+
+        assert_eq!(stopping_time(1u64, 1000), Ok(0));
+
+        assert_eq!(
+            stopping_time(0u64, 1000),
+            Err(Error::StepLimitReached { steps: 1000 })
+        );
+
+        assert_eq!(
+            stopping_time(27u8, 1000),
+            Err(Error::Overflow { index: 12 })
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::Relation;
+
+/// Serializes arrays of any length `K` as sequences, for fields such as those of [`Recurrence`](crate::Recurrence)
+///
+/// Serde only implements its traits for arrays of up to 32 elements with a length known in advance
+pub(crate) mod array {
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer, I: Serialize, const K: usize>(
+        array: &[I; K],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        array.as_slice().serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>, I: Deserialize<'de>, const K: usize>(
+        deserializer: D,
+    ) -> Result<[I; K], D::Error> {
+        deserializer.deserialize_seq(ArrayVisitor(PhantomData))
+    }
+
+    struct ArrayVisitor<I, const K: usize>(PhantomData<I>);
+
+    impl<'de, I: Deserialize<'de>, const K: usize> Visitor<'de> for ArrayVisitor<I, K> {
+        type Value = [I; K];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a sequence of {K} elements")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut error = None;
+            let elements: [Option<I>; K] =
+                core::array::from_fn(|position| match seq.next_element() {
+                    Ok(Some(element)) => Some(element),
+                    Ok(None) => {
+                        error.get_or_insert_with(|| de::Error::invalid_length(position, &self));
+                        None
+                    }
+                    Err(e) => {
+                        error.get_or_insert(e);
+                        None
+                    }
+                });
+
+            if let Some(error) = error {
+                return Err(error);
+            }
+
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(K + 1, &self));
+            }
+
+            // Every element is present, as any missing one would have set the error
+            Ok(elements.map(|element| element.expect("every element should be present")))
+        }
+    }
+}
+
+/// Serializes the state of the relation, in the same format as [`RelationState`](crate::RelationState)
+///
+/// The relation function cannot be serialized, so it must be provided again with
+/// [`Relation::from_state`] when the relation is deserialized
+impl<I: Serialize, F> Serialize for Relation<I, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("RelationState", 3)?;
+        state.serialize_field("starter", &self.starter)?;
+        state.serialize_field("current", &self.current_number)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AffineRelation, RelationState};
+
+    use super::*;
+
+    #[test]
+    fn test_relation_round_trip() {
+        let rule = |x: f64| x * 1.05;
+        let mut relation = Relation::new(100., rule);
+        relation.nth(2);
+
+        let json = serde_json::to_string(&relation).unwrap();
+
+        assert_eq!(json, r#"{"starter":100.0,"current":110.25,"index":2}"#);
+
+        let state: RelationState<f64> = serde_json::from_str(&json).unwrap();
+        let mut resumed = Relation::from_state(state, rule);
+
+        assert_eq!(resumed.index(), 2);
+
+        assert_eq!(resumed.nth(3), relation.nth(3));
+    }
+
+    #[test]
+    fn test_affine_round_trip() {
+        let mut relation = AffineRelation::new(5, 2, 3);
+        relation.next();
+
+        let json = serde_json::to_string(&relation).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"current":13,"index":1,"multiplier":2,"increment":3}"#
+        );
+
+        let resumed: AffineRelation<i32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(resumed, relation);
+    }
+}
@@ -0,0 +1,212 @@
+use wide::{f32x8, f64x4, CmpGt};
+
+use crate::{AffineRelation, Relation};
+
+/// A polynomial rule `t(n+1) = c0 + c1 * t(n) + c2 * t(n)^2 + ... + c(K-1) * t(n)^(K-1)`
+///
+/// As the rule is stored as data rather than a closure, batches of starting points can be advanced
+/// several lanes at a time with SIMD instructions, which is four lanes for [`f64`] and eight for [`f32`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Polynomial<T, const K: usize> {
+    coefficients: [T; K],
+}
+
+impl<T, const K: usize> Polynomial<T, K> {
+    /// Create a new [`Polynomial`] from its coefficients, beginning with the constant term
+    pub fn new(coefficients: [T; K]) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns the coefficients, beginning with the constant term
+    pub fn coefficients(&self) -> &[T; K] {
+        &self.coefficients
+    }
+}
+
+macro_rules! impl_simd {
+    ($float:ty, $lanes:ty, $width:literal) => {
+        impl<const K: usize> Polynomial<$float, K> {
+            /// Evaluates the polynomial at `x` with Horner's method
+            pub fn evaluate(&self, x: $float) -> $float {
+                self.coefficients
+                    .iter()
+                    .rev()
+                    .fold(0., |sum, &coefficient| sum * x + coefficient)
+            }
+
+            /// Evaluates the polynomial at every lane of `x`
+            fn evaluate_lanes(&self, x: $lanes) -> $lanes {
+                self.coefficients
+                    .iter()
+                    .rev()
+                    .fold(<$lanes>::splat(0.), |sum, &coefficient| {
+                        sum * x + <$lanes>::splat(coefficient)
+                    })
+            }
+
+            /// Returns the [`Relation`] following the polynomial rule from `starter`
+            pub fn relation(
+                self,
+                starter: $float,
+            ) -> Relation<$float, impl FnOnce($float) -> $float + Copy> {
+                Relation::new(starter, move |x| self.evaluate(x))
+            }
+
+            /// Advances every value of `values` by `steps` steps of the rule, in place
+            pub fn advance_batch(&self, values: &mut [$float], steps: usize) {
+                let mut chunks = values.chunks_exact_mut($width);
+
+                for chunk in &mut chunks {
+                    let mut lanes = <$lanes>::new(chunk.try_into().unwrap());
+
+                    for _ in 0..steps {
+                        lanes = self.evaluate_lanes(lanes);
+                    }
+
+                    chunk.copy_from_slice(&lanes.to_array());
+                }
+
+                for value in chunks.into_remainder() {
+                    for _ in 0..steps {
+                        *value = self.evaluate(*value);
+                    }
+                }
+            }
+
+            /// Finds the escape time of every value of `values`, writing it to the same position of `times`
+            ///
+            /// Each escape time is the number of steps before the magnitude of the term first exceeds `threshold`,
+            /// or [`None`] if it does not within `max_steps` steps, as given by [`Relation::escape_time`]
+            ///
+            /// # Panics
+            /// - If `times` is not the same length as `values`
+            pub fn escape_times(
+                &self,
+                values: &[$float],
+                threshold: $float,
+                max_steps: usize,
+                times: &mut [Option<usize>],
+            ) {
+                assert_eq!(
+                    values.len(),
+                    times.len(),
+                    "there should be an escape time for each value"
+                );
+
+                let mut chunks = values.chunks_exact($width);
+                let mut time_chunks = times.chunks_exact_mut($width);
+                let limit = <$lanes>::splat(threshold);
+
+                for (chunk, times) in (&mut chunks).zip(&mut time_chunks) {
+                    let mut lanes = <$lanes>::new(chunk.try_into().unwrap());
+                    // The lanes which have not escaped yet, one bit per lane
+                    let mut remaining: u32 = (1 << $width) - 1;
+
+                    times.fill(None);
+
+                    for step in 0..=max_steps {
+                        let escaped = lanes.abs().cmp_gt(limit).move_mask() as u32 & remaining;
+
+                        for (lane, time) in times.iter_mut().enumerate() {
+                            if escaped & (1 << lane) != 0 {
+                                *time = Some(step);
+                            }
+                        }
+
+                        remaining &= !escaped;
+
+                        if remaining == 0 || step == max_steps {
+                            break;
+                        }
+
+                        lanes = self.evaluate_lanes(lanes);
+                    }
+                }
+
+                for (&value, time) in chunks.remainder().iter().zip(time_chunks.into_remainder()) {
+                    *time = self.relation(value).escape_time(threshold, max_steps);
+                }
+            }
+        }
+
+        impl AffineRelation<$float> {
+            /// Applies the rule of the relation to every value of `values` for `steps` steps, in place
+            ///
+            /// The values are advanced several lanes at a time with SIMD instructions
+            pub fn advance_batch(&self, values: &mut [$float], steps: usize) {
+                Polynomial::new([*self.increment(), *self.multiplier()])
+                    .advance_batch(values, steps);
+            }
+        }
+    };
+}
+
+impl_simd!(f64, f64x4, 4);
+impl_simd!(f32, f32x8, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polynomial_batch() {
+        let logistic = Polynomial::new([0f64, 3.7, -3.7]);
+
+        let mut values: [f64; 7] = core::array::from_fn(|i| 0.1 + 0.1 * i as f64);
+        let expected = values.map(|value| logistic.relation(value).nth(100));
+
+        logistic.advance_batch(&mut values, 100);
+
+        assert_eq!(values, expected);
+
+        assert_eq!(Polynomial::new([1f32, 2., 3.]).evaluate(2.), 17.);
+    }
+
+    #[test]
+    fn test_affine_batch() {
+        let relation = AffineRelation::new(0f32, 0.5, 1.);
+
+        let mut values: [f32; 10] = core::array::from_fn(|i| i as f32);
+        let expected = values.map(|value| AffineRelation::new(value, 0.5, 1.).nth(20));
+
+        relation.advance_batch(&mut values, 20);
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_escape_times() {
+        // The real line of the Mandelbrot set, from the map x -> x^2 + c for each c, starting at 0
+        let cs: [f64; 9] = [-2.5, -2., -1., 0., 0.25, 0.3, 1., 2., 0.5];
+        let mut times = [None; 9];
+
+        for (c, time) in cs.iter().zip(&mut times) {
+            Polynomial::new([*c, 0., 1.]).escape_times(&[0.], 2., 100, core::slice::from_mut(time));
+        }
+
+        assert_eq!(
+            times,
+            [
+                Some(1),
+                None,
+                None,
+                None,
+                None,
+                Some(12),
+                Some(3),
+                Some(2),
+                Some(5)
+            ]
+        );
+
+        let doubling = Polynomial::new([0f64, 2.]);
+        let values = [1., 2., 0.5, -4., 0., 16., 0.25, 100., -0.75];
+        let mut times = [None; 9];
+
+        doubling.escape_times(&values, 100., 10, &mut times);
+
+        let expected = values.map(|value| doubling.relation(value).escape_time(100., 10));
+
+        assert_eq!(times, expected);
+    }
+}
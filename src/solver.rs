@@ -0,0 +1,130 @@
+use num_traits::{Float, Num};
+
+use crate::{Error, HigherOrderRelation, Relation};
+
+impl<I: Float> Relation<I, ()> {
+    /// Create a new [`Relation`] for Newton's method, where `x(n+1) = x(n) - f(x(n)) / f'(x(n))`
+    ///
+    /// The terms converge to a root of `f` from a close enough starter `x0`.
+    /// A zero derivative makes the next term infinite or `NaN`, which stops [`Relation::solve`] from converging
+    pub fn newton<F: Fn(I) -> I + Copy, D: Fn(I) -> I + Copy>(
+        f: F,
+        f_prime: D,
+        x0: I,
+    ) -> Relation<I, impl FnOnce(I) -> I + Copy> {
+        Relation::new(x0, move |x: I| x - f(x) / f_prime(x))
+    }
+
+    /// Create a new [`HigherOrderRelation`] for the secant method, which approximates Newton's method
+    /// from the last two terms rather than a derivative
+    ///
+    /// The terms converge to a root of `f` from close enough starters `x0` and `x1`.
+    /// Once the last two terms have the same value of `f`, such as when they have converged, the terms stop changing
+    pub fn secant<F: Fn(I) -> I + Copy>(
+        f: F,
+        x0: I,
+        x1: I,
+    ) -> HigherOrderRelation<I, impl FnOnce([I; 2]) -> I + Copy, 2> {
+        HigherOrderRelation::new([x0, x1], move |[previous, current]: [I; 2]| {
+            let slope = f(current) - f(previous);
+
+            if slope.is_zero() {
+                return current;
+            }
+
+            current - f(current) * (current - previous) / slope
+        })
+    }
+
+    /// Create a new [`Relation`] for damped fixed point iteration, where `x(n+1) = (1 - ω) x(n) + ω g(x(n))`
+    ///
+    /// A relaxation factor `omega` below `1` damps oscillating iterations so they converge, and a factor above `1` speeds
+    /// up slow ones. Either way the fixed points are the same as those of `g`
+    pub fn damped<G: Fn(I) -> I + Copy>(
+        g: G,
+        omega: I,
+        x0: I,
+    ) -> Relation<I, impl FnOnce(I) -> I + Copy> {
+        Relation::new(x0, move |x: I| (I::one() - omega) * x + omega * g(x))
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Returns the limit the relation converges to from the current number, without updating the struct
+    ///
+    /// See [`Relation::converge`] for when the relation is considered to have converged
+    ///
+    /// # Errors
+    /// - If the terms are still not within `tolerance` of each other after `max_steps` steps
+    pub fn solve(&self, tolerance: I, max_steps: usize) -> Result<I, Error> {
+        self.clone()
+            .converge(tolerance, max_steps)
+            .map(|convergence| convergence.limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newton() {
+        let mut sqrt_two = Relation::newton(|x| x * x - 2., |x| 2. * x, 1.);
+
+        assert_eq!(sqrt_two.calculate_next(), 1.5);
+
+        let root = sqrt_two.solve(1e-12, 20).unwrap();
+
+        assert!((root - 2f64.sqrt()).abs() < 1e-12);
+
+        // Newton's method converges quadratically
+        assert!((sqrt_two.nth(5) - 2f64.sqrt()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_secant() {
+        let mut sqrt_two = Relation::secant(|x| x * x - 2., 1., 2.);
+
+        assert!((sqrt_two.calculate_next() - 4. / 3.).abs() < 1e-15);
+
+        assert!((sqrt_two.nth(10) - 2f64.sqrt()).abs() < 1e-15);
+
+        // Converged terms stay put instead of dividing by zero
+        assert_eq!(sqrt_two.nth(5), sqrt_two.terms()[0]);
+    }
+
+    #[test]
+    fn test_damped() {
+        // Plain iteration of 3 - x oscillates forever between two values
+        let plain = Relation::new(0., |x: f64| 3. - x);
+
+        assert!(plain.solve(1e-12, 1000).is_err());
+
+        let damped = Relation::damped(|x| 3. - x, 0.5, 0.);
+
+        assert_eq!(damped.solve(1e-12, 1000), Ok(1.5));
+
+        // Damping cos near its fixed point cancels most of its derivative, so it converges much faster
+        let plain_steps = Relation::new(1., |x: f64| x.cos())
+            .converge(1e-12, 1000)
+            .unwrap()
+            .steps;
+        let damped = Relation::damped(|x: f64| x.cos(), 0.6, 1.)
+            .converge(1e-12, 1000)
+            .unwrap();
+
+        assert!((damped.limit - 0.739_085_133_215_160_6).abs() < 1e-10);
+
+        assert!(damped.steps * 5 < plain_steps);
+    }
+
+    #[test]
+    fn test_newton_zero_derivative() {
+        let flat = Relation::newton(|x: f64| x * x + 1., |x| 2. * x, 0.);
+
+        assert_eq!(
+            flat.solve(1e-12, 20),
+            Err(Error::DidNotConverge { steps: 20 })
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use num_traits::{Num, ToPrimitive};
+
+use crate::Relation;
+
+/// A small inline SVG line chart of a run of terms, written with [`fmt::Display`]
+///
+/// Created by [`Relation::sparkline`]. It has no axes or labels, and is drawn with `currentColor` by default,
+/// so it takes on the colour of the text around it when embedded in a page
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sparkline {
+    values: Vec<f64>,
+    width: u32,
+    height: u32,
+    stroke: String,
+}
+
+impl<I: Num + Clone + ToPrimitive, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Creates a [`Sparkline`] of the next `count` terms, beginning with the current number, without updating the struct
+    ///
+    /// Terms which cannot be converted to a finite `f64` are left out
+    pub fn sparkline(&self, count: usize) -> Sparkline {
+        Sparkline::new(self.clone().take(count).filter_map(|term| term.to_f64()))
+    }
+}
+
+impl Sparkline {
+    /// Create a new [`Sparkline`] of `values`, 100 by 20 pixels
+    pub fn new(values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            values: values
+                .into_iter()
+                .filter(|value| value.is_finite())
+                .collect(),
+            width: 100,
+            height: 20,
+            stroke: String::from("currentColor"),
+        }
+    }
+
+    /// Sets the width and height of the sparkline, in pixels
+    pub fn size(self, width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            ..self
+        }
+    }
+
+    /// Sets the colour of the line, which can be any SVG colour such as `#1f77b4`
+    ///
+    /// The colour is escaped when written, so it cannot add markup to the page
+    pub fn stroke(self, stroke: impl Into<String>) -> Self {
+        Self {
+            stroke: stroke.into(),
+            ..self
+        }
+    }
+
+    /// Returns the values drawn by the sparkline
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+}
+
+impl fmt::Display for Sparkline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            width,
+            height,
+            stroke,
+            ..
+        } = self;
+
+        write!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\
+             <polyline fill=\"none\" stroke=\""
+        )?;
+
+        write_escaped(f, stroke)?;
+        f.write_str("\" stroke-width=\"1\" points=\"")?;
+
+        let (low, high) = self
+            .values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), &value| {
+                (low.min(value), high.max(value))
+            });
+
+        let width = f64::from(*width);
+        let height = f64::from(*height);
+        let step = width / self.values.len().saturating_sub(1).max(1) as f64;
+
+        for (position, &value) in self.values.iter().enumerate() {
+            // A constant run is drawn as a flat line through the middle
+            let y = if high > low {
+                height - (value - low) / (high - low) * height
+            } else {
+                height / 2.
+            };
+
+            if position > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{:.2},{:.2}", step * position as f64, y)?;
+        }
+
+        f.write_str("\"/></svg>")
+    }
+}
+
+/// Writes `text` as the value of a quoted XML attribute, escaping the characters which could end it or start markup
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for character in text.chars() {
+        match character {
+            '&' => f.write_str("&amp;")?,
+            '<' => f.write_str("&lt;")?,
+            '>' => f.write_str("&gt;")?,
+            '"' => f.write_str("&quot;")?,
+            '\'' => f.write_str("&apos;")?,
+            _ => write!(f, "{character}")?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_sparkline() {
+        let sparkline = Relation::new(0, |x| x + 1).sparkline(3).size(10, 4);
+
+        assert_eq!(
+            sparkline.to_string(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"10\" height=\"4\" viewBox=\"0 0 10 4\">\
+             <polyline fill=\"none\" stroke=\"currentColor\" stroke-width=\"1\" points=\"0.00,4.00 5.00,2.00 10.00,0.00\"/></svg>"
+        );
+
+        let constant = Sparkline::new([3., f64::NAN, 3.]).stroke("red");
+
+        assert_eq!(constant.values(), [3., 3.]);
+
+        assert!(constant
+            .to_string()
+            .contains("stroke=\"red\" stroke-width=\"1\" points=\"0.00,10.00 100.00,10.00\""));
+    }
+
+    #[test]
+    fn test_stroke_escaped() {
+        let sparkline = Sparkline::new([1.]).stroke("\"/><script>alert('&')</script>");
+
+        assert!(sparkline.to_string().contains(
+            "stroke=\"&quot;/&gt;&lt;script&gt;alert(&apos;&amp;&apos;)&lt;/script&gt;\" stroke-width"
+        ));
+
+        assert!(!sparkline.to_string().contains("<script>"));
+    }
+}
@@ -0,0 +1,13 @@
+/// The saved state of a [`Relation`](crate::Relation), without its relation function
+///
+/// A relation can be resumed from its state with [`Relation::from_state`](crate::Relation::from_state)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationState<I> {
+    /// The starter number, at index `0`
+    pub starter: I,
+    /// The current number
+    pub current: I,
+    /// The index of the current number
+    pub index: usize,
+}
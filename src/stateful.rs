@@ -0,0 +1,129 @@
+use num_traits::Num;
+
+/// A relation whose relation function may keep its own state between steps
+///
+/// Unlike [`Relation`](crate::Relation), the relation function is only required to be [`FnMut`],
+/// so it can capture counters, random number generators, or anything else it needs to update.
+/// As calling the relation function may change its state, every method that calculates a number takes `&mut self`
+#[derive(Debug, Clone)]
+pub struct StatefulRelation<I, F> {
+    current_number: I,
+    index: usize,
+    relation: F,
+}
+
+impl<I: Num + Clone, F: FnMut(I) -> I> StatefulRelation<I, F> {
+    /// Create a new [`StatefulRelation`]
+    pub fn new(starter: I, relation: F) -> Self {
+        Self {
+            current_number: starter,
+            index: 0,
+            relation,
+        }
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Calculates and returns the next number in the sequence, without updating the current number
+    ///
+    /// The relation function is still called, so any state it keeps will be updated
+    pub fn calculate_next(&mut self) -> I {
+        (self.relation)(self.current_number.clone())
+    }
+
+    /// Calculates the next number in the sequence and updates the struct
+    pub fn next(&mut self) {
+        self.current_number = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`StatefulRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_number.clone()
+    }
+
+    /// Consumes the relation, returning its relation function along with any state it has built up
+    pub fn into_relation(self) -> F {
+        self.relation
+    }
+}
+
+impl<I: Num + Clone, F: FnMut(I) -> I> Iterator for StatefulRelation<I, F> {
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        StatefulRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_relation() {
+        let mut calls = 0;
+        let mut relation = StatefulRelation::new(0, move |x| {
+            calls += 1;
+            x + calls
+        });
+
+        assert_eq!(relation.nth(4), 10);
+
+        assert_eq!(relation.index(), 4);
+
+        let mut rule = relation.into_relation();
+
+        assert_eq!(rule(0), 5);
+    }
+
+    fn random_walk(mut seed: u32) -> StatefulRelation<i64, impl FnMut(i64) -> i64> {
+        // A linear congruential generator decides whether each step goes up or down
+        StatefulRelation::new(0, move |x| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+
+            if seed & 0x8000 == 0 {
+                x + 1
+            } else {
+                x - 1
+            }
+        })
+    }
+
+    #[test]
+    fn test_pseudo_random_relation() {
+        let mut walk = random_walk(12345);
+        let mut previous = walk.nth(0);
+
+        for _ in 0..100 {
+            let current = walk.nth(1);
+
+            assert_eq!((current - previous).abs(), 1);
+
+            previous = current;
+        }
+
+        assert!(random_walk(12345)
+            .take(100)
+            .eq(random_walk(12345).take(100)));
+    }
+}
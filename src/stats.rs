@@ -0,0 +1,223 @@
+use num_traits::Float;
+
+use crate::{Relation, RelationLike};
+
+/// Summary statistics over a run of terms, calculated in one pass with Welford's algorithm
+///
+/// Created by [`Relation::stats`], or updated term by term by [`RunningStats`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats<I> {
+    count: usize,
+    mean: I,
+    // The sum of squared differences from the mean
+    m2: I,
+    min: I,
+    max: I,
+    last: I,
+}
+
+/// A relation which yields each term alongside the statistics of every term so far
+///
+/// Created by [`Relation::running_stats`]. The statistics are updated online with Welford's algorithm,
+/// so displaying a cumulative mean or maximum while iterating takes constant memory
+#[derive(Debug, Copy, Clone)]
+pub struct RunningStats<I, F> {
+    relation: Relation<I, F>,
+    stats: Stats<I>,
+}
+
+impl<I: Float> Stats<I> {
+    /// Create [`Stats`] from a single term
+    pub(crate) fn from_term(term: I) -> Self {
+        Self {
+            count: 1,
+            mean: term,
+            m2: I::zero(),
+            min: term,
+            max: term,
+            last: term,
+        }
+    }
+
+    /// Adds a term to the statistics
+    pub(crate) fn push(&mut self, term: I) {
+        self.count += 1;
+
+        let count = I::from(self.count).unwrap_or_else(I::nan);
+        let delta = term - self.mean;
+
+        self.mean = self.mean + delta / count;
+        self.m2 = self.m2 + delta * (term - self.mean);
+        self.min = self.min.min(term);
+        self.max = self.max.max(term);
+        self.last = term;
+    }
+
+    /// Returns the number of terms
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the terms
+    pub fn mean(&self) -> I {
+        self.mean
+    }
+
+    /// Returns the population variance of the terms
+    pub fn variance(&self) -> I {
+        self.m2 / I::from(self.count).unwrap_or_else(I::nan)
+    }
+
+    /// Returns the sample variance of the terms, which is [`None`] for a single term
+    pub fn sample_variance(&self) -> Option<I> {
+        (self.count > 1).then(|| self.m2 / I::from(self.count - 1).unwrap_or_else(I::nan))
+    }
+
+    /// Returns the population standard deviation of the terms
+    pub fn std_dev(&self) -> I {
+        self.variance().sqrt()
+    }
+
+    /// Returns the smallest term
+    pub fn min(&self) -> I {
+        self.min
+    }
+
+    /// Returns the largest term
+    pub fn max(&self) -> I {
+        self.max
+    }
+
+    /// Returns the last term
+    pub fn last(&self) -> I {
+        self.last
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Calculates summary statistics over `count` terms, beginning with the current number, without updating the struct
+    ///
+    /// The terms are not stored, so this runs in constant memory however many terms there are.
+    /// Returns [`None`] if `count` is zero
+    pub fn stats(&self, count: usize) -> Option<Stats<I>> {
+        RelationLike::stats(self, count)
+    }
+
+    /// Wraps the relation so that it keeps running statistics of its terms, beginning with the current number
+    pub fn running_stats(self) -> RunningStats<I, F> {
+        RunningStats {
+            stats: Stats::from_term(self.current_number),
+            relation: self,
+        }
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> RunningStats<I, F> {
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        self.relation.current()
+    }
+
+    /// Returns the index of the current number, where the starter is at index `0`
+    pub fn index(&self) -> usize {
+        self.relation.index
+    }
+
+    /// Returns the statistics of every term up to and including the current number
+    pub fn stats(&self) -> &Stats<I> {
+        &self.stats
+    }
+
+    /// Calculates the next number in the sequence, adds it to the statistics, and updates the struct
+    pub fn next(&mut self) {
+        self.relation.next();
+        self.stats.push(self.relation.current_number);
+    }
+
+    /// Calls [`RunningStats::next`] `index` times, and then returns the current number
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.relation.current_number
+    }
+}
+
+impl<I: Float, F: FnOnce(I) -> I + Copy> Iterator for RunningStats<I, F> {
+    type Item = (I, Stats<I>);
+
+    /// Yields the current number with the statistics up to and including it, and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = (self.relation.current_number, self.stats);
+        RunningStats::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let relation = Relation::new(1., |x| x + 1.);
+
+        let stats = relation.stats(5).unwrap();
+
+        assert_eq!(stats.count(), 5);
+
+        assert_eq!(stats.mean(), 3.);
+
+        assert_eq!(stats.variance(), 2.);
+
+        assert_eq!(stats.sample_variance(), Some(2.5));
+
+        assert_eq!((stats.min(), stats.max(), stats.last()), (1., 5., 5.));
+
+        assert_eq!(relation.stats(0), None);
+
+        assert_eq!(relation.stats(1).unwrap().sample_variance(), None);
+    }
+
+    #[test]
+    fn test_running_stats() {
+        let mut running = Relation::new(4., |x| x / 2.).running_stats();
+
+        let (term, stats) = Iterator::next(&mut running).unwrap();
+
+        assert_eq!((term, stats.count(), stats.max()), (4., 1, 4.));
+
+        let (term, stats) = Iterator::next(&mut running).unwrap();
+
+        assert_eq!((term, stats.mean(), stats.min()), (2., 3., 2.));
+
+        running.nth(2);
+
+        assert_eq!(running.index(), 4);
+
+        assert_eq!(running.stats().count(), 5);
+
+        // The running statistics match the statistics calculated in one go
+        assert_eq!(
+            running.stats(),
+            &Relation::new(4., |x| x / 2.).stats(5).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_oscillating_stats() {
+        let stats = Relation::new(2., |x: f64| -x).stats(1_000_000).unwrap();
+
+        assert!(stats.mean().abs() < 1e-12);
+
+        assert!((stats.std_dev() - 2.).abs() < 1e-12);
+
+        assert_eq!(stats.last(), -2.);
+    }
+}
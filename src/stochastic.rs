@@ -0,0 +1,157 @@
+use num_traits::Num;
+use rand::{Rng, SeedableRng};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "alloc")]
+mod monte_carlo;
+
+#[cfg(feature = "alloc")]
+pub use monte_carlo::MonteCarlo;
+
+/// A relation whose rule also draws from a random number generator, such as `t(n+1) = t(n) + ε(n)`
+///
+/// Takes a relation function, a starter number, and a random number generator.
+/// The relation function is given the current number and the generator, and returns the next number.
+/// With a seeded generator the relation is reproducible, producing the same terms for the same seed
+#[derive(Debug, Clone)]
+pub struct StochasticRelation<I, F, R> {
+    starter: I,
+    current_number: I,
+    index: usize,
+    relation: F,
+    rng: R,
+}
+
+impl<I: Num + Clone, F: FnOnce(I, &mut R) -> I + Copy, R: Rng> StochasticRelation<I, F, R> {
+    /// Create a new [`StochasticRelation`]
+    pub fn new(starter: I, rng: R, relation: F) -> Self {
+        Self {
+            current_number: starter.clone(),
+            starter,
+            index: 0,
+            relation,
+            rng,
+        }
+    }
+
+    /// Create a new [`StochasticRelation`] with a generator seeded from `seed`, so that its terms are reproducible
+    pub fn seeded(starter: I, seed: u64, relation: F) -> Self
+    where
+        R: SeedableRng,
+    {
+        Self::new(starter, R::seed_from_u64(seed), relation)
+    }
+
+    /// Returns the starter number
+    pub fn starter(&self) -> &I {
+        &self.starter
+    }
+
+    /// Returns the current number
+    pub fn current(&self) -> &I {
+        &self.current_number
+    }
+
+    /// Returns the index of the current number
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the random number generator
+    pub fn rng(&self) -> &R {
+        &self.rng
+    }
+
+    /// Resets the relation to its starter number, with a new generator
+    ///
+    /// Resetting with a generator seeded the same way reproduces the same terms
+    pub fn reset(&mut self, rng: R) {
+        self.current_number = self.starter.clone();
+        self.index = 0;
+        self.rng = rng;
+    }
+
+    /// Calculates the next number in the sequence, drawing from the generator, and updates the struct
+    pub fn next(&mut self) {
+        self.current_number = (self.relation)(self.current_number.clone(), &mut self.rng);
+        self.index += 1;
+    }
+
+    /// Calls [`StochasticRelation::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> I {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_number.clone()
+    }
+
+    /// Returns the parts of the relation, which are its current number, its index, and its generator
+    pub fn into_parts(self) -> (I, usize, R) {
+        (self.current_number, self.index, self.rng)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I, &mut R) -> I + Copy, R: Rng> Iterator
+    for StochasticRelation<I, F, R>
+{
+    type Item = I;
+
+    /// Yields the current number and then advances the relation
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_number.clone();
+        StochasticRelation::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn noisy(x: f64, rng: &mut StdRng) -> f64 {
+        x + rng.random_range(-1.0..1.0)
+    }
+
+    #[test]
+    fn test_stochastic_relation() {
+        let mut relation = StochasticRelation::seeded(0., 7, noisy);
+
+        let term = relation.nth(10);
+
+        assert_eq!(relation.index(), 10);
+
+        assert!(term.abs() < 10.);
+
+        assert_ne!(term, 0.);
+
+        // A rule which ignores the generator is deterministic
+        let counter = StochasticRelation::seeded(0, 7, |x, _: &mut StdRng| x + 1);
+
+        assert!(counter.take(3).eq([0, 1, 2]));
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let mut first = StochasticRelation::seeded(0., 42, noisy);
+        let second = StochasticRelation::seeded(0., 42, noisy);
+        let other_seed = StochasticRelation::seeded(0., 43, noisy);
+
+        assert!(first.clone().take(20).eq(second.take(20)));
+
+        assert!(!first.clone().take(20).eq(other_seed.take(20)));
+
+        let term = first.nth(5);
+
+        first.reset(StdRng::seed_from_u64(42));
+
+        assert_eq!(first.nth(5), term);
+    }
+}
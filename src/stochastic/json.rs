@@ -0,0 +1,100 @@
+use std::io::{self, Write};
+
+use num_traits::Num;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::{
+    json::{write_line, Record},
+    StochasticRelation,
+};
+
+impl<
+        I: Num + Clone + Serialize,
+        F: FnOnce(I, &mut R) -> I + Copy,
+        R: Rng + SeedableRng + Clone,
+    > StochasticRelation<I, F, R>
+{
+    /// Writes `paths` independent paths of `steps` steps each as JSON Lines, beginning with the current number,
+    /// without updating the struct
+    ///
+    /// The paths draw from the same generators as [`StochasticRelation::monte_carlo`], and each line
+    /// is an object such as `{"index":1,"value":101.2,"path":0}`
+    ///
+    /// # Errors
+    /// - If a term cannot be serialized, or writing to `writer` fails
+    pub fn export_monte_carlo_json_lines(
+        &self,
+        mut writer: impl Write,
+        paths: usize,
+        steps: usize,
+    ) -> io::Result<()> {
+        let mut seeder = self.rng.clone();
+
+        for path in 0..paths {
+            let rng = R::from_rng(&mut seeder);
+            let relation = StochasticRelation::new(self.current_number.clone(), rng, self.relation);
+
+            for (index, value) in relation.take(steps.saturating_add(1)).enumerate() {
+                write_line(
+                    &mut writer,
+                    &Record::<_, ()> {
+                        index,
+                        value: &value,
+                        param: None,
+                        path: Some(path),
+                    },
+                )?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec::Vec};
+
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn test_export_monte_carlo_json_lines() {
+        let walk = StochasticRelation::<f64, _, StdRng>::seeded(0., 7, |x, rng| {
+            x + rng.random_range(-1.0..1.0)
+        });
+
+        let mut output = Vec::new();
+        walk.export_monte_carlo_json_lines(&mut output, 3, 4)
+            .unwrap();
+
+        let records: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(records.len(), 15);
+
+        assert_eq!(records[5]["path"], 1);
+
+        assert_eq!(records[5]["index"], 0);
+
+        // The terminal values match those summarised by a Monte Carlo run of the same relation
+        let summary = walk.monte_carlo(3, 4);
+        let mut terminal: Vec<f64> = records
+            .iter()
+            .filter(|record| record["index"] == 4)
+            .map(|record| record["value"].as_f64().unwrap())
+            .collect();
+
+        terminal.sort_by(f64::total_cmp);
+
+        assert!(terminal
+            .iter()
+            .zip(summary.terminal())
+            .all(|(value, expected)| (value - expected).abs() < 1e-12));
+    }
+}
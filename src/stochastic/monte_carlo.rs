@@ -0,0 +1,326 @@
+use alloc::vec::Vec;
+use core::{cmp::Ordering, convert::Infallible};
+
+use num_traits::Float;
+use rand::{Rng, SeedableRng};
+
+use crate::{Histogram, Interrupted, RunControl, StochasticRelation};
+
+/// The summary of many independent paths of a [`StochasticRelation`]
+///
+/// Created by [`StochasticRelation::monte_carlo`]. Step `0` is the number the paths began from,
+/// and the last step holds the terminal values
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarlo<I> {
+    means: Vec<I>,
+    // The terms of every path at each step, sorted in ascending order
+    steps: Vec<Vec<I>>,
+}
+
+impl<I: Float, F: FnOnce(I, &mut R) -> I + Copy, R: Rng + SeedableRng + Clone>
+    StochasticRelation<I, F, R>
+{
+    /// Simulates `paths` independent paths of `steps` steps each, beginning with the current number,
+    /// without updating the struct
+    ///
+    /// Each path draws from its own generator, seeded from the relation's generator,
+    /// so the summary is reproducible for a seeded relation
+    pub fn monte_carlo(&self, paths: usize, steps: usize) -> MonteCarlo<I> {
+        let paths = self
+            .path_rngs(paths)
+            .into_iter()
+            .map(|rng| self.path(rng, steps))
+            .collect();
+
+        MonteCarlo::from_paths(paths)
+    }
+
+    /// Runs [`StochasticRelation::monte_carlo`] within the limits of `control`, where every step of every path counts
+    ///
+    /// # Errors
+    /// - If the run is stopped by `control`, with the summary of the paths which were completed as the partial result
+    pub fn monte_carlo_controlled(
+        &self,
+        paths: usize,
+        steps: usize,
+        control: &RunControl,
+    ) -> Result<MonteCarlo<I>, Interrupted<MonteCarlo<I>>> {
+        let guard = control.start();
+        let mut completed = Vec::with_capacity(paths.min(1024));
+        let mut taken = 0;
+
+        for rng in self.seeded_rngs().take(paths) {
+            let path = self.path_checked(rng, steps, || {
+                guard.check(taken)?;
+                taken += 1;
+
+                Ok(())
+            });
+
+            match path {
+                Ok(terms) => completed.push(terms),
+                Err(reason) => {
+                    return Err(Interrupted {
+                        reason,
+                        steps: taken,
+                        partial: MonteCarlo::from_paths(completed),
+                    })
+                }
+            }
+        }
+
+        Ok(MonteCarlo::from_paths(completed))
+    }
+
+    /// Returns a generator for each of `paths` paths, seeded in turn from the relation's generator
+    pub(crate) fn path_rngs(&self, paths: usize) -> Vec<R> {
+        self.seeded_rngs().take(paths).collect()
+    }
+
+    /// Returns an endless run of generators, seeded in turn from the relation's generator
+    fn seeded_rngs(&self) -> impl Iterator<Item = R> {
+        let mut seeder = self.rng.clone();
+
+        core::iter::repeat_with(move || R::from_rng(&mut seeder))
+    }
+
+    /// Returns the `steps + 1` terms of a path beginning with the current number, drawing from `rng`
+    pub(crate) fn path(&self, rng: R, steps: usize) -> Vec<I> {
+        match self.path_checked(rng, steps, || Ok::<(), Infallible>(())) {
+            Ok(terms) => terms,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Builds a path like [`StochasticRelation::path`], calling `before_step` before each step and
+    /// stopping with its error if it returns one
+    fn path_checked<E>(
+        &self,
+        rng: R,
+        steps: usize,
+        mut before_step: impl FnMut() -> Result<(), E>,
+    ) -> Result<Vec<I>, E> {
+        let mut path = StochasticRelation::new(self.current_number, rng, self.relation);
+        // The number of steps may be far more than fits in memory, so only a little is reserved up front
+        let mut terms = Vec::with_capacity(steps.saturating_add(1).min(1024));
+
+        terms.push(self.current_number);
+
+        for _ in 0..steps {
+            before_step()?;
+
+            path.next();
+            terms.push(*path.current());
+        }
+
+        Ok(terms)
+    }
+}
+
+impl<I: Float> MonteCarlo<I> {
+    /// Summarises `paths`, which all hold the same number of terms, with no steps at all if there are no paths
+    pub(crate) fn from_paths(paths: Vec<Vec<I>>) -> Self {
+        let length = paths.first().map_or(0, Vec::len);
+
+        let mut terms: Vec<Vec<I>> = (0..length)
+            .map(|step| paths.iter().map(|path| path[step]).collect())
+            .collect();
+
+        let count = I::from(paths.len()).unwrap_or_else(I::nan);
+
+        let means = terms
+            .iter()
+            .map(|step| step.iter().fold(I::zero(), |sum, &term| sum + term) / count)
+            .collect();
+
+        for step in terms.iter_mut() {
+            step.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        }
+
+        MonteCarlo {
+            means,
+            steps: terms,
+        }
+    }
+
+    /// Returns the number of paths simulated
+    pub fn paths(&self) -> usize {
+        self.steps.first().map_or(0, Vec::len)
+    }
+
+    /// Returns the number of steps in each path, which is `0` if there are no paths
+    pub fn steps(&self) -> usize {
+        self.steps.len().saturating_sub(1)
+    }
+
+    /// Returns the mean across the paths at each step
+    pub fn means(&self) -> &[I] {
+        &self.means
+    }
+
+    /// Returns the mean across the paths at `step`, or [`None`] if the paths are not that long
+    pub fn mean(&self, step: usize) -> Option<I> {
+        self.means.get(step).copied()
+    }
+
+    /// Returns the terms of every path at `step`, sorted in ascending order
+    pub fn terms(&self, step: usize) -> Option<&[I]> {
+        self.steps.get(step).map(Vec::as_slice)
+    }
+
+    /// Returns the terminal values of every path, sorted in ascending order
+    pub fn terminal(&self) -> &[I] {
+        self.steps.last().map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the `quantile` across the paths at `step`, such as `0.05` for the 5th percentile,
+    /// interpolating linearly between the closest paths
+    ///
+    /// Returns [`None`] if the paths are not that long, there are no paths, or `quantile` is not between `0` and `1`
+    pub fn percentile(&self, step: usize, quantile: I) -> Option<I> {
+        let terms = self.steps.get(step)?;
+
+        if terms.is_empty() || !(I::zero()..=I::one()).contains(&quantile) {
+            return None;
+        }
+
+        let position = quantile * I::from(terms.len() - 1)?;
+        let lower = position.floor();
+        let fraction = position - lower;
+        let lower = lower.to_usize()?;
+
+        let below = terms[lower];
+        let above = terms.get(lower + 1).copied().unwrap_or(below);
+
+        Some(below + (above - below) * fraction)
+    }
+
+    /// Returns the `quantile` across the paths at each step, as given by [`MonteCarlo::percentile`]
+    pub fn percentiles(&self, quantile: I) -> Vec<Option<I>> {
+        (0..self.steps.len())
+            .map(|step| self.percentile(step, quantile))
+            .collect()
+    }
+
+    /// Counts the terminal values into `bins` equal width bins, covering the smallest to largest of them
+    pub fn terminal_histogram(&self, bins: usize) -> Histogram<I> {
+        let terminal = self.terminal();
+
+        let (low, high) = match (terminal.first(), terminal.last()) {
+            (Some(&low), Some(&high)) => (low, high),
+            _ => (I::zero(), I::zero()),
+        };
+
+        let mut histogram = Histogram::new(low, high, bins);
+
+        for &term in terminal {
+            histogram.add(term);
+        }
+
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::finance::random_walk;
+
+    #[test]
+    fn test_monte_carlo() {
+        let walk = random_walk(100., 1., 2., 1., StdRng::seed_from_u64(9));
+
+        let summary = walk.monte_carlo(5000, 25);
+
+        assert_eq!(summary.paths(), 5000);
+
+        assert_eq!(summary.steps(), 25);
+
+        assert_eq!(summary.mean(0), Some(100.));
+
+        assert_eq!(summary.percentile(0, 0.5), Some(100.));
+
+        // After 25 steps the walk has drifted by 25, with a standard deviation of 10
+        assert!((summary.mean(25).unwrap() - 125.).abs() < 1.);
+
+        let median = summary.percentile(25, 0.5).unwrap();
+        let lower = summary.percentile(25, 0.05).unwrap();
+        let upper = summary.percentile(25, 0.95).unwrap();
+
+        assert!((median - 125.).abs() < 1.);
+
+        assert!((upper - lower - 2. * 1.645 * 10.).abs() < 2.);
+
+        assert_eq!(summary.percentile(26, 0.5), None);
+
+        assert_eq!(summary.percentile(25, 1.5), None);
+
+        assert_eq!(
+            summary
+                .terminal_histogram(10)
+                .counts()
+                .iter()
+                .sum::<usize>(),
+            5000
+        );
+
+        assert_eq!(walk.monte_carlo(5000, 25), summary);
+    }
+
+    #[test]
+    fn test_controlled_monte_carlo() {
+        let walk = random_walk(0., 0., 1., 1., StdRng::seed_from_u64(5));
+
+        assert_eq!(
+            walk.monte_carlo_controlled(10, 20, &RunControl::new()),
+            Ok(walk.monte_carlo(10, 20))
+        );
+
+        let interrupted = walk
+            .monte_carlo_controlled(10, 20, &RunControl::new().with_max_steps(50))
+            .unwrap_err();
+
+        assert_eq!(interrupted.steps, 50);
+
+        assert_eq!(interrupted.partial.paths(), 2);
+
+        assert_eq!(
+            interrupted.partial.terms(20),
+            walk.monte_carlo(2, 20).terms(20)
+        );
+
+        let interrupted = walk
+            .monte_carlo_controlled(10, usize::MAX, &RunControl::new().with_max_steps(1000))
+            .unwrap_err();
+
+        assert_eq!(interrupted.steps, 1000);
+
+        assert_eq!(interrupted.partial.paths(), 0);
+
+        assert_eq!(interrupted.partial.steps(), 0);
+    }
+
+    #[test]
+    fn test_percentile_interpolation() {
+        let steady = StochasticRelation::seeded(0., 1, |x, _: &mut StdRng| x + 1.);
+
+        let summary = steady.monte_carlo(4, 2);
+
+        assert_eq!(summary.terminal(), [2.; 4]);
+
+        assert_eq!(summary.percentiles(0.25), [Some(0.), Some(1.), Some(2.)]);
+
+        let spread = MonteCarlo {
+            means: vec![2.5],
+            steps: vec![vec![1., 2., 3., 4.]],
+        };
+
+        assert_eq!(spread.percentile(0, 0.5), Some(2.5));
+
+        assert_eq!(spread.percentile(0, 1.), Some(4.));
+    }
+}
@@ -0,0 +1,153 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use num_traits::Num;
+
+use crate::Relation;
+
+/// The number of terms a [`RelationStream`] yields before giving other tasks a chance to run
+const BUDGET: usize = 64;
+
+/// A relation exposed as an endless [`Stream`] of its terms
+///
+/// Created by [`Relation::stream`]. Every term is ready immediately, so to avoid starving other tasks,
+/// the stream hands control back to the executor after every 64 terms
+#[derive(Debug, Copy, Clone)]
+pub struct RelationStream<I, F> {
+    relation: Relation<I, F>,
+    budget: usize,
+}
+
+/// A relation exposed as a [`Stream`] which yields one term for each item of a stream of ticks
+///
+/// Created by [`Relation::stream_per_tick`]. The ticks are usually from a timer, such as an interval,
+/// which limits the rate of the terms. The stream ends when the ticks do
+#[derive(Debug, Copy, Clone)]
+pub struct TickedStream<I, F, T> {
+    relation: Relation<I, F>,
+    ticks: T,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Turns the relation into a [`Stream`] of its terms, beginning with the current number
+    pub fn stream(self) -> RelationStream<I, F> {
+        RelationStream {
+            relation: self,
+            budget: BUDGET,
+        }
+    }
+
+    /// Turns the relation into a [`Stream`] which yields the next term, beginning with the current number,
+    /// whenever `ticks` yields an item
+    pub fn stream_per_tick<T: Stream>(self, ticks: T) -> TickedStream<I, F, T> {
+        TickedStream {
+            relation: self,
+            ticks,
+        }
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> RelationStream<I, F> {
+    /// Returns the underlying relation, whose current number is the next term to be yielded
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Returns the underlying relation
+    pub fn into_inner(self) -> Relation<I, F> {
+        self.relation
+    }
+}
+
+impl<I: Num + Clone + Unpin, F: FnOnce(I) -> I + Copy + Unpin> Stream for RelationStream<I, F> {
+    type Item = I;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+
+        if stream.budget == 0 {
+            stream.budget = BUDGET;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        stream.budget -= 1;
+
+        Poll::Ready(Iterator::next(&mut stream.relation))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy, T> TickedStream<I, F, T> {
+    /// Returns the underlying relation, whose current number is the next term to be yielded
+    pub fn inner(&self) -> &Relation<I, F> {
+        &self.relation
+    }
+
+    /// Returns the underlying relation and the stream of ticks
+    pub fn into_parts(self) -> (Relation<I, F>, T) {
+        (self.relation, self.ticks)
+    }
+}
+
+impl<I: Num + Clone + Unpin, F: FnOnce(I) -> I + Copy + Unpin, T: Stream + Unpin> Stream
+    for TickedStream<I, F, T>
+{
+    type Item = I;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+
+        match Pin::new(&mut stream.ticks).poll_next(cx) {
+            Poll::Ready(Some(_)) => Poll::Ready(Iterator::next(&mut stream.relation)),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ticks.size_hint()
+    }
+}
+
+// The executor the tests run on needs `std`
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let terms: Vec<i32> = block_on(Relation::new(1, |x| x * 2).stream().take(5).collect());
+
+        assert_eq!(terms, [1, 2, 4, 8, 16]);
+
+        // The stream keeps going past its budget, after yielding to the executor
+        let mut counter = Relation::new(0, |x| x + 1).stream();
+
+        let terms: Vec<u32> = block_on((&mut counter).take(200).collect());
+
+        assert_eq!(terms.last(), Some(&199));
+
+        assert_eq!(counter.inner().current(), &200);
+    }
+
+    #[test]
+    fn test_stream_per_tick() {
+        let ticks = stream::iter(0..3);
+
+        let terms: Vec<i32> =
+            block_on(Relation::new(5, |x| x - 1).stream_per_tick(ticks).collect());
+
+        assert_eq!(terms, [5, 4, 3]);
+    }
+}
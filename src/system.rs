@@ -0,0 +1,197 @@
+use num_traits::Num;
+
+use crate::{abs_diff, trace, Convergence, Error};
+
+/// A system of `N` coupled relations, whose state is an array updated all at once
+///
+/// Takes a relation function and a starter state.
+/// The relation function is given every component of the current state and returns every component of the next,
+/// so each component may depend on all of the others, as in a predator and prey model
+#[derive(Debug, Copy, Clone)]
+pub struct System<I, F, const N: usize> {
+    starter: [I; N],
+    current_state: [I; N],
+    index: usize,
+    relation: F,
+}
+
+impl<I: Num + Clone, F: FnOnce([I; N]) -> [I; N] + Copy, const N: usize> System<I, F, N> {
+    /// Create a new [`System`]
+    pub fn new(starter: [I; N], relation: F) -> Self {
+        Self {
+            current_state: starter.clone(),
+            starter,
+            index: 0,
+            relation,
+        }
+    }
+
+    /// Returns the starter state
+    pub fn starter(&self) -> &[I; N] {
+        &self.starter
+    }
+
+    /// Returns the current state
+    pub fn current(&self) -> &[I; N] {
+        &self.current_state
+    }
+
+    /// Returns the index of the current state
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Resets the system to its starter state
+    pub fn reset(&mut self) {
+        self.current_state = self.starter.clone();
+        self.index = 0;
+    }
+
+    /// Calculates and returns the next state
+    pub fn calculate_next(&self) -> [I; N] {
+        (self.relation)(self.current_state.clone())
+    }
+
+    /// Calculates the next state and updates the struct
+    pub fn next(&mut self) {
+        self.current_state = self.calculate_next();
+        self.index += 1;
+    }
+
+    /// Calls [`System::next`] `index` times, and then returns the result
+    pub fn nth(&mut self, index: usize) -> [I; N] {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current_state.clone()
+    }
+
+    /// Calculates the state `index` steps after the current state, without updating the struct
+    pub fn nth_calculated(&self, index: usize) -> [I; N] {
+        self.clone().nth(index)
+    }
+}
+
+impl<I: Num + Clone + PartialOrd, F: FnOnce([I; N]) -> [I; N] + Copy, const N: usize>
+    System<I, F, N>
+{
+    /// Calls [`System::next`] until every component differs from its previous value by less than `tolerance`
+    ///
+    /// Returns the last state as the estimated limit, along with the number of steps taken
+    ///
+    /// # Errors
+    /// - If the states are still not within `tolerance` of each other after `max_steps` steps
+    pub fn converge(
+        &mut self,
+        tolerance: I,
+        max_steps: usize,
+    ) -> Result<Convergence<[I; N]>, Error> {
+        trace::span!(
+            "converge_system",
+            start_index = self.index,
+            max_steps,
+            components = N
+        );
+
+        for steps in 1..=max_steps {
+            let previous = self.current_state.clone();
+            self.next();
+
+            let converged = self
+                .current_state
+                .iter()
+                .zip(&previous)
+                .all(|(current, previous)| abs_diff(current, previous) < tolerance);
+
+            if converged {
+                trace::event!(DEBUG, steps, "converged");
+
+                return Ok(Convergence {
+                    limit: self.current_state.clone(),
+                    steps,
+                });
+            }
+        }
+
+        trace::event!(WARN, steps = max_steps, "did not converge");
+
+        Err(Error::DidNotConverge { steps: max_steps })
+    }
+}
+
+impl<I: Num + Clone, F: FnOnce([I; N]) -> [I; N] + Copy, const N: usize> Iterator
+    for System<I, F, N>
+{
+    type Item = [I; N];
+
+    /// Yields the current state and then advances the system
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current_state.clone();
+        System::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coupled_accounts() {
+        // Each period, a tenth of the first account is moved into the second, and 100 is deposited into the first
+        let mut accounts = System::new([1000, 0], |[first, second]| {
+            [first - first / 10 + 100, second + first / 10]
+        });
+
+        assert_eq!(accounts.calculate_next(), [1000, 100]);
+
+        assert_eq!(accounts.nth(3), [1000, 300]);
+
+        assert_eq!(accounts.index(), 3);
+
+        accounts.reset();
+
+        assert!(accounts.take(2).eq([[1000, 0], [1000, 100]]));
+    }
+
+    #[test]
+    fn test_predator_prey() {
+        let model = System::new([100., 10.], |[prey, predators]: [f64; 2]| {
+            [
+                prey + 0.1 * prey - 0.01 * prey * predators,
+                predators - 0.1 * predators + 0.001 * prey * predators,
+            ]
+        });
+
+        let [prey, predators] = model.nth_calculated(1);
+
+        assert!((prey - 100.).abs() < 1e-12);
+
+        assert!((predators - 10.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_system_convergence() {
+        let mut system = System::new([0., 0.], |[x, y]: [f64; 2]| {
+            [0.5 * x + 0.25 * y, 0.25 * x + 0.5 * y + 1.]
+        });
+
+        let convergence = system.converge(1e-12, 1000).unwrap();
+
+        assert!((convergence.limit[0] - 4. / 3.).abs() < 1e-10);
+
+        assert!((convergence.limit[1] - 8. / 3.).abs() < 1e-10);
+
+        let mut diverging = System::new([1, 1], |[x, y]| [x + y, x]);
+
+        assert_eq!(
+            diverging.converge(1, 20),
+            Err(Error::DidNotConverge { steps: 20 })
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "parse")]
+use num_traits::Float;
+use num_traits::Num;
+
+#[cfg(feature = "parse")]
+use crate::ExpressionRelation;
+use crate::{Metadata, Relation, RelationLike};
+
+/// An aligned text table of the index and value of a run of terms, written with [`fmt::Display`]
+///
+/// Created by [`Relation::table`]. Values are right aligned, and floating point values can be
+/// written with a fixed number of decimal places with [`Table::precision`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table<I> {
+    first_index: usize,
+    terms: Vec<I>,
+    precision: Option<usize>,
+    metadata: Option<Metadata>,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Creates a [`Table`] of the next `count` terms, beginning with the current number, without updating the struct
+    pub fn table(&self, count: usize) -> Table<I> {
+        RelationLike::table(self, count)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl<I: Float> ExpressionRelation<I> {
+    /// Creates a [`Table`] of the next `count` terms, beginning with the current number, without updating the struct
+    pub fn table(&self, count: usize) -> Table<I> {
+        RelationLike::table(self, count)
+    }
+}
+
+impl<I> Table<I> {
+    /// Create a new [`Table`] of `terms`, where the first term is at `first_index`
+    pub fn new(first_index: usize, terms: Vec<I>) -> Self {
+        Self {
+            first_index,
+            terms,
+            precision: None,
+            metadata: None,
+        }
+    }
+
+    /// Writes each value with `precision` decimal places, which has no effect on integer values
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Heads the column of terms with the label and unit of `metadata`
+    pub fn with_metadata(self, metadata: Metadata) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// Returns the terms in the table
+    pub fn terms(&self) -> &[I] {
+        &self.terms
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Table<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const INDEX: &str = "n";
+        const VALUE: &str = "t(n)";
+
+        let heading = self
+            .metadata
+            .as_ref()
+            .map_or_else(|| String::from(VALUE), |metadata| metadata.heading(VALUE));
+
+        let values: Vec<String> = self
+            .terms
+            .iter()
+            .map(|term| match self.precision {
+                Some(precision) => format!("{term:.precision$}"),
+                None => format!("{term}"),
+            })
+            .collect();
+
+        let last_index = self.first_index + self.terms.len().saturating_sub(1);
+        let index_width = format!("{last_index}").len().max(INDEX.len());
+        let value_width = values
+            .iter()
+            .map(String::len)
+            .fold(heading.chars().count(), usize::max);
+
+        writeln!(f, "{INDEX:>index_width$} | {heading:>value_width$}")?;
+        writeln!(f, "{:-<index_width$}-+-{:-<value_width$}", "", "")?;
+
+        for (offset, value) in values.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>index_width$} | {value:>value_width$}",
+                self.first_index + offset
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let table = Relation::new(1, |x| x * 10).table(4);
+
+        assert_eq!(
+            table.to_string(),
+            "n | t(n)\n\
+             --+-----\n\
+             0 |    1\n\
+             1 |   10\n\
+             2 |  100\n\
+             3 | 1000\n"
+        );
+    }
+
+    #[test]
+    fn test_table_precision() {
+        let mut relation = Relation::new(1000., |x| x * 1.05);
+        relation.nth(9);
+
+        let table = relation.table(2).precision(2);
+
+        assert_eq!(
+            table.to_string(),
+            " n |    t(n)\n\
+             ---+--------\n\
+             \x209 | 1551.33\n\
+             10 | 1628.89\n"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Instrumentation with `tracing`, which compiles away to nothing without the `tracing` feature
+
+/// Emits a `tracing` event at the given level, such as `trace::event!(DEBUG, steps, "converged")`
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::event!(tracing::Level::$level, $($arg)+);
+    };
+}
+
+/// Enters a `tracing` span at the debug level, until the end of the enclosing block
+macro_rules! span {
+    ($name:literal $(, $($field:tt)+)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($name $(, $($field)+)?).entered();
+    };
+}
+
+pub(crate) use event;
+pub(crate) use span;
+
+#[cfg(all(test, feature = "tracing", feature = "std"))]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tracing::{span, subscriber, Event, Metadata, Subscriber};
+
+    use crate::{CheckedRelation, Error, Relation};
+
+    /// Counts the spans and events it is sent
+    #[derive(Clone, Default)]
+    struct Counter {
+        spans: Arc<AtomicUsize>,
+        events: Arc<AtomicUsize>,
+    }
+
+    impl Subscriber for Counter {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(self.spans.fetch_add(1, Ordering::Relaxed) as u64 + 1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, _: &Event<'_>) {
+            self.events.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn test_tracing() {
+        let counter = Counter::default();
+
+        subscriber::with_default(counter.clone(), || {
+            let mut relation = Relation::new(1., |x: f64| x / 2.);
+
+            assert!(relation.converge(1e-6, 100).is_ok());
+
+            let mut overflowing = CheckedRelation::new(u8::MAX, |x: u8| x.checked_add(1));
+
+            assert_eq!(overflowing.next(), Err(Error::Overflow { index: 1 }));
+        });
+
+        assert_eq!(counter.spans.load(Ordering::Relaxed), 1);
+
+        assert_eq!(counter.events.load(Ordering::Relaxed), 2);
+    }
+}
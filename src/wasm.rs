@@ -0,0 +1,88 @@
+use std::{string::ToString, vec::Vec};
+
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use crate::ExpressionRelation;
+
+/// An [`ExpressionRelation`] of `f64` terms exported to JavaScript as `Relation`
+///
+/// The rule is parsed from a string such as `1.08*x + 100`, so a page can let its users type in recurrences to explore.
+/// Build the module with `cargo rustc --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib`,
+/// and then generate the JavaScript glue with `wasm-bindgen`
+#[wasm_bindgen(js_name = Relation)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmRelation {
+    relation: ExpressionRelation<f64>,
+}
+
+#[wasm_bindgen(js_class = Relation)]
+impl WasmRelation {
+    /// Create a new relation by parsing `rule`, beginning at `start`
+    ///
+    /// # Errors
+    /// - If the rule is not a valid expression, as a JavaScript `Error`
+    #[wasm_bindgen(constructor)]
+    pub fn new(rule: &str, start: f64) -> Result<WasmRelation, JsError> {
+        ExpressionRelation::parse(rule, start)
+            .map(|relation| Self { relation })
+            .map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Returns the current number
+    #[wasm_bindgen(getter)]
+    pub fn current(&self) -> f64 {
+        *self.relation.current()
+    }
+
+    /// Returns the index of the current number, where the starting number is at index `0`
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> usize {
+        self.relation.index()
+    }
+
+    /// Calculates and returns the next number, without updating the relation
+    #[wasm_bindgen(js_name = calculateNext)]
+    pub fn calculate_next(&self) -> f64 {
+        self.relation.calculate_next()
+    }
+
+    /// Advances the relation by one step, and then returns the new current number
+    ///
+    /// This is `next` in JavaScript
+    #[wasm_bindgen(js_name = next)]
+    pub fn advance(&mut self) -> f64 {
+        self.relation.next();
+
+        self.current()
+    }
+
+    /// Advances the relation by `index` steps, and then returns the new current number
+    pub fn nth(&mut self, index: usize) -> f64 {
+        self.relation.nth(index)
+    }
+
+    /// Returns the next `count` terms as a `Float64Array`, beginning with the current number, without updating the relation
+    pub fn terms(&self, count: usize) -> Vec<f64> {
+        self.relation.clone().take(count).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasm_relation() {
+        let mut relation = WasmRelation::new("1.08*x + 100", 0.).unwrap();
+
+        assert_eq!(relation.terms(3), [0., 100., 208.]);
+
+        assert_eq!(relation.advance(), 100.);
+
+        assert_eq!(relation.index(), 1);
+
+        assert!((relation.nth(2) - 324.64).abs() < 1e-9);
+
+        assert_eq!(relation.current(), relation.terms(1)[0]);
+    }
+}
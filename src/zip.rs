@@ -0,0 +1,142 @@
+use num_traits::Num;
+
+use crate::Relation;
+
+/// Two relations advanced in lockstep, whose terms are combined pointwise
+///
+/// Created by [`Relation::zip_with`]. The `n`th term is `combine(a(n), b(n))`,
+/// such as a portfolio which is the sum of its stocks and bonds
+#[derive(Debug, Copy, Clone)]
+pub struct ZipWith<I, F, J, G, C> {
+    left: Relation<I, F>,
+    right: Relation<J, G>,
+    combine: C,
+}
+
+impl<I: Num + Clone, F: FnOnce(I) -> I + Copy> Relation<I, F> {
+    /// Combines the relation with `other`, so that each term is `combine` applied to the terms of both at the same step
+    pub fn zip_with<J: Num + Clone, G: FnOnce(J) -> J + Copy, O, C: FnOnce(I, J) -> O + Copy>(
+        self,
+        other: Relation<J, G>,
+        combine: C,
+    ) -> ZipWith<I, F, J, G, C> {
+        ZipWith {
+            left: self,
+            right: other,
+            combine,
+        }
+    }
+}
+
+impl<I, F, J, G, O, C> ZipWith<I, F, J, G, C>
+where
+    I: Num + Clone,
+    F: FnOnce(I) -> I + Copy,
+    J: Num + Clone,
+    G: FnOnce(J) -> J + Copy,
+    C: FnOnce(I, J) -> O + Copy,
+{
+    /// Returns the combination of the current numbers of both relations
+    pub fn current(&self) -> O {
+        (self.combine)(
+            self.left.current_number.clone(),
+            self.right.current_number.clone(),
+        )
+    }
+
+    /// Returns the first of the combined relations
+    pub fn left(&self) -> &Relation<I, F> {
+        &self.left
+    }
+
+    /// Returns the second of the combined relations
+    pub fn right(&self) -> &Relation<J, G> {
+        &self.right
+    }
+
+    /// Calculates and returns the next combined number, without updating the struct
+    pub fn calculate_next(&self) -> O {
+        (self.combine)(self.left.calculate_next(), self.right.calculate_next())
+    }
+
+    /// Advances both relations by one step
+    pub fn next(&mut self) {
+        self.left.next();
+        self.right.next();
+    }
+
+    /// Calls [`ZipWith::next`] `index` times, and then returns the combined number
+    pub fn nth(&mut self, index: usize) -> O {
+        for _ in 0..index {
+            self.next();
+        }
+
+        self.current()
+    }
+
+    /// Separates the combined relations
+    pub fn into_parts(self) -> (Relation<I, F>, Relation<J, G>) {
+        (self.left, self.right)
+    }
+}
+
+impl<I, F, J, G, O, C> Iterator for ZipWith<I, F, J, G, C>
+where
+    I: Num + Clone,
+    F: FnOnce(I) -> I + Copy,
+    J: Num + Clone,
+    G: FnOnce(J) -> J + Copy,
+    C: FnOnce(I, J) -> O + Copy,
+{
+    type Item = O;
+
+    /// Yields the current combined number and then advances both relations
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current();
+        ZipWith::next(self);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_with() {
+        let stocks = Relation::new(1000, |x| x * 2);
+        let bonds = Relation::new(500, |x| x + 50);
+
+        let mut portfolio = stocks.zip_with(bonds, |a, b| a + b);
+
+        assert_eq!(portfolio.current(), 1500);
+
+        assert_eq!(portfolio.calculate_next(), 2550);
+
+        assert_eq!(portfolio.nth(2), 4600);
+
+        assert_eq!(portfolio.left().index(), 2);
+
+        assert_eq!(portfolio.right().index(), 2);
+
+        assert!(portfolio.take(2).eq([4600, 8650]));
+    }
+
+    #[test]
+    fn test_zip_different_types() {
+        let units = Relation::new(10u32, |x| x + 1);
+        let price = Relation::new(2.5, |x| x * 2.);
+
+        let value = units.zip_with(price, |units, price| f64::from(units) * price);
+
+        assert!(value.take(3).eq([25., 55., 120.]));
+
+        let (units, _) = value.into_parts();
+
+        assert_eq!(units.current(), &10);
+    }
+}